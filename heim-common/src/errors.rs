@@ -7,6 +7,7 @@ use std::io;
 use std::num;
 use std::path::PathBuf;
 use std::result;
+use std::string;
 
 #[cfg(feature = "backtrace")]
 use backtrace::Backtrace;
@@ -54,6 +55,11 @@ pub enum Context {
         /// Name of the called function.
         func: Cow<'static, str>,
     },
+    /// Operation is not supported for the current platform.
+    NotSupported {
+        /// Name of the operation.
+        operation: Cow<'static, str>,
+    },
     /// Human-readable details for error.
     Message {
         /// Details text.
@@ -119,6 +125,24 @@ impl Error {
         self.source.kind()
     }
 
+    /// Returns `true` if error was caused by the insufficient permissions,
+    /// ex. if some file can be read by the privileged users only.
+    pub fn is_permission_denied(&self) -> bool {
+        self.source.kind() == io::ErrorKind::PermissionDenied
+    }
+
+    /// Returns `true` if error was caused by the operation timeout,
+    /// see [with_timeout](./fn.with_timeout.html) function.
+    pub fn is_timed_out(&self) -> bool {
+        self.source.kind() == io::ErrorKind::TimedOut
+    }
+
+    /// Returns `true` if error was caused by the operation
+    /// which is not supported for the current platform, see [not_supported](./fn.not_supported.html).
+    pub fn is_not_supported(&self) -> bool {
+        matches!(self.context, Some(Context::NotSupported { .. }))
+    }
+
     /// Creates a new instance of an `Error` from a particular OS error code.
     ///
     /// This method is considered to be an internal API
@@ -275,6 +299,49 @@ impl Error {
     }
 }
 
+/// Returns error representing an operation which is not supported for the current platform.
+///
+/// Platform-specific functions should be called under the corresponding `cfg` attributes,
+/// but there are also functions available for all platforms which are not implemented
+/// for some of them yet; these are failing with this error at runtime,
+/// so callers can skip them gracefully with the [Error::is_not_supported] check.
+///
+/// ```rust
+/// # use heim_common::not_supported;
+/// let e = not_supported("per-CPU frequencies");
+/// assert!(e.is_not_supported());
+/// assert_eq!(e.to_string(), "operation not supported: per-CPU frequencies");
+/// ```
+///
+/// [Error::is_not_supported]: ./struct.Error.html#method.is_not_supported
+pub fn not_supported<T>(operation: T) -> Error
+where
+    T: Into<Cow<'static, str>>,
+{
+    Error::new(
+        io::Error::from(io::ErrorKind::Other),
+        Context::NotSupported {
+            operation: operation.into(),
+        },
+    )
+}
+
+// Human-readable names for the `sysconf(3)` parameters used across `heim-*` crates
+#[cfg(unix)]
+fn sysconf_name(name: libc::c_int) -> Option<&'static str> {
+    match name {
+        libc::_SC_CLK_TCK => Some("_SC_CLK_TCK"),
+        libc::_SC_PAGESIZE => Some("_SC_PAGESIZE"),
+        libc::_SC_NPROCESSORS_ONLN => Some("_SC_NPROCESSORS_ONLN"),
+        _ => None,
+    }
+}
+
+#[cfg(not(unix))]
+fn sysconf_name(_name: libc::c_int) -> Option<&'static str> {
+    None
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match &self.context {
@@ -284,9 +351,10 @@ impl fmt::Display for Error {
             Some(Context::SysCtlByName { ref name }) => {
                 f.write_fmt(format_args!("sysctlbyname \"{:?}\" failed", name))
             }
-            Some(Context::SysConf { name }) => {
-                f.write_fmt(format_args!("sysconf \"{}\" failed", name))
-            }
+            Some(Context::SysConf { name }) => match sysconf_name(*name) {
+                Some(name) => f.write_fmt(format_args!("sysconf({}) failed", name)),
+                None => f.write_fmt(format_args!("sysconf \"{}\" failed", name)),
+            },
             Some(Context::MissingKey { name, source }) => {
                 // TODO: That's ugly
                 if source.len() > 0 {
@@ -302,6 +370,10 @@ impl fmt::Display for Error {
                 "Unable to parse \"{}\", unsupported format",
                 path.display()
             )),
+            Some(Context::NotSupported { operation }) => {
+                // Underlying `io::Error` is a placeholder and it has no useful details
+                return f.write_fmt(format_args!("operation not supported: {}", operation));
+            }
             Some(Context::Message { text }) => f.write_str(text.as_ref()),
             Some(Context::Ffi { func }) => {
                 f.write_fmt(format_args!("FFI function \"{}\" call failed", func))
@@ -352,6 +424,20 @@ impl From<num::ParseFloatError> for Error {
     }
 }
 
+impl From<string::FromUtf8Error> for Error {
+    fn from(e: string::FromUtf8Error) -> Self {
+        let inner = io::Error::new(io::ErrorKind::InvalidData, e);
+        Self::from(inner)
+    }
+}
+
+impl From<string::FromUtf16Error> for Error {
+    fn from(e: string::FromUtf16Error) -> Self {
+        let inner = io::Error::new(io::ErrorKind::InvalidData, e);
+        Self::from(inner)
+    }
+}
+
 // See https://github.com/heim-rs/heim/issues/182
 impl From<convert::Infallible> for Error {
     fn from(e: convert::Infallible) -> Self {
@@ -372,3 +458,56 @@ impl From<nix::Error> for Error {
         Error::from(inner)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::{not_supported, Error};
+
+    #[test]
+    fn test_is_permission_denied() {
+        assert!(
+            Error::from(io::Error::from(io::ErrorKind::PermissionDenied)).is_permission_denied()
+        );
+        assert!(!Error::from(io::Error::from(io::ErrorKind::NotFound)).is_permission_denied());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_is_permission_denied_os_error() {
+        assert!(Error::from_raw_os_error(libc::EACCES).is_permission_denied());
+        assert!(!Error::from_raw_os_error(libc::ENOENT).is_permission_denied());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_from_nix_error() {
+        let e = Error::from(nix::Error::Sys(nix::errno::Errno::EPERM));
+        assert_eq!(e.raw_os_error(), Some(libc::EPERM));
+        assert!(e.to_string().contains("Operation not permitted"));
+
+        let e = Error::from(nix::Error::InvalidUtf8);
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert!(!e.to_string().is_empty());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_sysconf_display() {
+        let e = Error::from_raw_os_error(libc::EPERM).with_sysconf(libc::_SC_CLK_TCK);
+        assert!(e
+            .to_string()
+            .starts_with("sysconf(_SC_CLK_TCK) failed: Operation not permitted"));
+    }
+
+    #[test]
+    fn test_not_supported() {
+        let e = not_supported("protocol counters");
+        assert!(e.is_not_supported());
+        assert!(!e.is_permission_denied());
+        assert_eq!(e.to_string(), "operation not supported: protocol counters");
+
+        assert!(!Error::from(io::Error::from(io::ErrorKind::Other)).is_not_supported());
+    }
+}
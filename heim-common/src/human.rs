@@ -0,0 +1,108 @@
+//! Human-readable formatting for the measurement units.
+//!
+//! ```rust
+//! # use heim_common::human;
+//! # use heim_common::units::{information, time, Information, Time};
+//! let size = Information::new::<information::mebibyte>(1536);
+//! assert_eq!(human::bytes(size), "1.5 GiB");
+//!
+//! let uptime = Time::new::<time::second>(200.0);
+//! assert_eq!(human::duration(uptime), "3m 20s");
+//! ```
+
+use crate::units::{information, time, Information, Time};
+
+const IEC_SUFFIXES: [&str; 6] = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+const MINUTE: u64 = 60;
+const HOUR: u64 = 60 * MINUTE;
+const DAY: u64 = 24 * HOUR;
+
+/// Formats information amount with the binary (IEC) suffixes, ex. `1.5 GiB`.
+///
+/// Values less than one kibibyte are formatted in bytes as is, ex. `512 B`.
+pub fn bytes(value: Information) -> String {
+    let bytes = value.get::<information::byte>();
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut suffix = IEC_SUFFIXES[0];
+    for next in IEC_SUFFIXES.iter().skip(1) {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        suffix = next;
+    }
+
+    format!("{:.1} {}", value, suffix)
+}
+
+/// Formats time duration as a days, hours, minutes and seconds sequence, ex. `1d 2h 3m 20s`.
+///
+/// Zero components are omitted and fractional seconds are truncated,
+/// except for the durations less than one second, which are formatted
+/// in milliseconds, ex. `250ms`.
+///
+/// Negative and non-finite durations are formatted as `0s`.
+pub fn duration(value: Time) -> String {
+    let seconds = value.get::<time::second>();
+    if !seconds.is_finite() || seconds <= 0.0 {
+        return "0s".to_string();
+    }
+    if seconds < 1.0 {
+        return format!("{}ms", (seconds * 1_000.0) as u64);
+    }
+
+    let mut rest = seconds as u64;
+    let mut parts = Vec::with_capacity(4);
+    for (unit, suffix) in [(DAY, "d"), (HOUR, "h"), (MINUTE, "m"), (1, "s")].iter() {
+        let amount = rest / unit;
+        rest %= unit;
+        if amount > 0 {
+            parts.push(format!("{}{}", amount, suffix));
+        }
+    }
+
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bytes, duration};
+    use crate::units::{information, time, Information, Time};
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(bytes(Information::new::<information::byte>(0)), "0 B");
+        assert_eq!(bytes(Information::new::<information::byte>(1023)), "1023 B");
+        assert_eq!(
+            bytes(Information::new::<information::byte>(1024)),
+            "1.0 KiB"
+        );
+        assert_eq!(
+            bytes(Information::new::<information::mebibyte>(1536)),
+            "1.5 GiB"
+        );
+        assert_eq!(
+            bytes(Information::new::<information::byte>(u64::MAX)),
+            "16.0 EiB"
+        );
+    }
+
+    #[test]
+    fn test_duration() {
+        assert_eq!(duration(Time::new::<time::second>(0.0)), "0s");
+        assert_eq!(duration(Time::new::<time::second>(-5.0)), "0s");
+        assert_eq!(duration(Time::new::<time::millisecond>(250.0)), "250ms");
+        assert_eq!(duration(Time::new::<time::second>(45.9)), "45s");
+        assert_eq!(duration(Time::new::<time::second>(200.0)), "3m 20s");
+        assert_eq!(duration(Time::new::<time::second>(3605.0)), "1h 5s");
+        assert_eq!(
+            duration(Time::new::<time::second>(93_800.0)),
+            "1d 2h 3m 20s"
+        );
+    }
+}
@@ -27,14 +27,22 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 mod errors;
+pub mod human;
 mod macros;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serialize;
 #[doc(hidden)]
 pub mod sys;
+mod temperature;
+mod timeout;
 pub mod units;
 #[doc(hidden)]
 pub mod utils;
 
-pub use self::errors::{Context, Error, Result};
+pub use self::errors::{not_supported, Context, Error, Result};
+pub use self::temperature::Temperature;
+pub use self::timeout::{stream_with_timeout, with_timeout};
 
 /// Process identifier type.
 #[cfg(unix)]
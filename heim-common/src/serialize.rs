@@ -0,0 +1,175 @@
+//! Serialization helpers for the measurement units.
+//!
+//! `uom` quantities are serialized as plain numbers in the canonical units:
+//!
+//!  * [Information] as bytes
+//!  * [InformationRate] as bytes per second
+//!  * [Time] as seconds
+//!  * [Frequency] as hertz
+//!  * [Ratio] as a fraction, ex. `0.5` for the 50%
+//!  * [ThermodynamicTemperature] as degrees Celsius
+//!
+//! Functions are intended to be used with the `#[serde(serialize_with = "...")]` attribute,
+//! while the [Quantity] wrapper applies them to the values in the manual `Serialize`
+//! implementations.
+//!
+//! [Quantity]: ./struct.Quantity.html
+//! [Information]: ../units/type.Information.html
+//! [InformationRate]: ../units/type.InformationRate.html
+//! [Time]: ../units/type.Time.html
+//! [Frequency]: ../units/type.Frequency.html
+//! [Ratio]: ../units/type.Ratio.html
+//! [ThermodynamicTemperature]: ../units/type.ThermodynamicTemperature.html
+
+use serde::{Serialize, Serializer};
+
+use crate::units::{
+    frequency, information, information_rate, ratio, thermodynamic_temperature, time, Frequency,
+    Information, InformationRate, Ratio, ThermodynamicTemperature, Time,
+};
+
+/// Wrapper which serializes the borrowed quantity (or an optional one)
+/// in the canonical units with the corresponding helper function.
+///
+/// ```rust
+/// # use heim_common::serialize::Quantity;
+/// # use heim_common::units::{information, Information};
+/// let size = Information::new::<information::kilobyte>(1);
+/// assert_eq!(serde_json::to_string(&Quantity(&size)).unwrap(), "1000");
+/// ```
+#[derive(Debug)]
+pub struct Quantity<'a, T>(pub &'a T);
+
+macro_rules! serialize_quantity {
+    ($(#[$meta:meta])* $name:ident, $(#[$option_meta:meta])* $option_name:ident, $quantity:ty, $unit:ty) => {
+        $(#[$meta])*
+        pub fn $name<S>(value: &$quantity, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            value.get::<$unit>().serialize(serializer)
+        }
+
+        $(#[$option_meta])*
+        pub fn $option_name<S>(value: &Option<$quantity>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            value.map(|value| value.get::<$unit>()).serialize(serializer)
+        }
+
+        impl Serialize for Quantity<'_, $quantity> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                $name(self.0, serializer)
+            }
+        }
+
+        impl Serialize for Quantity<'_, Option<$quantity>> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                $option_name(self.0, serializer)
+            }
+        }
+    };
+}
+
+serialize_quantity!(
+    /// Serializes [Information](../units/type.Information.html) as bytes.
+    information,
+    /// Serializes optional [Information](../units/type.Information.html) as bytes.
+    option_information,
+    Information,
+    information::byte
+);
+serialize_quantity!(
+    /// Serializes [InformationRate](../units/type.InformationRate.html) as bytes per second.
+    information_rate,
+    /// Serializes optional [InformationRate](../units/type.InformationRate.html) as bytes per second.
+    option_information_rate,
+    InformationRate,
+    information_rate::byte_per_second
+);
+serialize_quantity!(
+    /// Serializes [Time](../units/type.Time.html) as seconds.
+    time,
+    /// Serializes optional [Time](../units/type.Time.html) as seconds.
+    option_time,
+    Time,
+    time::second
+);
+serialize_quantity!(
+    /// Serializes [Frequency](../units/type.Frequency.html) as hertz.
+    frequency,
+    /// Serializes optional [Frequency](../units/type.Frequency.html) as hertz.
+    option_frequency,
+    Frequency,
+    frequency::hertz
+);
+serialize_quantity!(
+    /// Serializes [Ratio](../units/type.Ratio.html) as a fraction.
+    ratio,
+    /// Serializes optional [Ratio](../units/type.Ratio.html) as a fraction.
+    option_ratio,
+    Ratio,
+    ratio::ratio
+);
+serialize_quantity!(
+    /// Serializes [ThermodynamicTemperature](../units/type.ThermodynamicTemperature.html)
+    /// as degrees Celsius.
+    thermodynamic_temperature,
+    /// Serializes optional [ThermodynamicTemperature](../units/type.ThermodynamicTemperature.html)
+    /// as degrees Celsius.
+    option_thermodynamic_temperature,
+    ThermodynamicTemperature,
+    thermodynamic_temperature::degree_celsius
+);
+
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+
+    use super::Quantity;
+    use crate::units::{frequency, information, time, Frequency, Information, Time};
+
+    #[derive(Serialize)]
+    struct Sample {
+        #[serde(serialize_with = "super::information")]
+        size: Information,
+        #[serde(serialize_with = "super::option_time")]
+        elapsed: Option<Time>,
+    }
+
+    #[test]
+    fn test_serialize() {
+        let sample = Sample {
+            size: Information::new::<information::kibibyte>(2),
+            elapsed: Some(Time::new::<time::millisecond>(1500.0)),
+        };
+        let json = serde_json::to_string(&sample).unwrap();
+        assert_eq!(json, r#"{"size":2048,"elapsed":1.5}"#);
+
+        let sample = Sample {
+            size: Information::new::<information::byte>(0),
+            elapsed: None,
+        };
+        let json = serde_json::to_string(&sample).unwrap();
+        assert_eq!(json, r#"{"size":0,"elapsed":null}"#);
+    }
+
+    #[test]
+    fn test_serialize_quantity() {
+        let value = Time::new::<time::millisecond>(250.0);
+        assert_eq!(serde_json::to_string(&Quantity(&value)).unwrap(), "0.25");
+
+        let value = Some(Frequency::new::<frequency::kilohertz>(2));
+        assert_eq!(serde_json::to_string(&Quantity(&value)).unwrap(), "2000");
+
+        let value: Option<Frequency> = None;
+        assert_eq!(serde_json::to_string(&Quantity(&value)).unwrap(), "null");
+    }
+}
@@ -0,0 +1,52 @@
+//! Linux-specific routines used across `heim` crates.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{Error, Result};
+
+/// Reads the sysfs or procfs file with a single value, ex. `/sys/class/hwmon/hwmon0/name`.
+///
+/// Value is trimmed from the surrounding whitespace and trailing `\n`.
+/// Missing file is not an error, as the optional attributes are not exposed
+/// by some drivers or kernels, `None` is returned in that case.
+pub fn read_value<T: AsRef<Path>>(path: T) -> Result<Option<String>> {
+    let path = path.as_ref();
+    match fs::read_to_string(path) {
+        Ok(value) => Ok(Some(value.trim().to_string())),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(Error::from(e).with_file(path)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::read_value;
+
+    #[test]
+    fn test_read_value() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("name");
+        fs::write(&path, "coretemp\n").unwrap();
+
+        assert_eq!(read_value(&path).unwrap().as_deref(), Some("coretemp"));
+    }
+
+    #[test]
+    fn test_read_value_missing() {
+        let dir = tempfile::tempdir().unwrap();
+
+        assert_eq!(read_value(dir.path().join("missing")).unwrap(), None);
+    }
+
+    #[test]
+    fn test_read_value_error() {
+        let dir = tempfile::tempdir().unwrap();
+
+        // Reading a directory fails with `EISDIR`
+        assert!(read_value(dir.path()).is_err());
+    }
+}
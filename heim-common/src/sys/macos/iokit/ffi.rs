@@ -38,6 +38,14 @@ extern "C" {
         existing: *mut io_iterator_t,
     ) -> kern_return::kern_return_t;
 
+    // https://developer.apple.com/documentation/iokit/1514536-ioregistryentryfrompath?language=objc
+    // Returned entry should be released with `IOObjectRelease`,
+    // `MACH_PORT_NULL` is returned if there is no entry with that path.
+    pub fn IORegistryEntryFromPath(
+        masterPort: mach_port_t,
+        path: *const c_char,
+    ) -> io_registry_entry_t;
+
     // https://developer.apple.com/documentation/iokit/1514454-ioregistryentrygetparententry?language=objc
     // The caller should release `parent` with `IOObjectRelease`
     pub fn IORegistryEntryGetParentEntry(
@@ -3,7 +3,7 @@ use std::mem;
 use core_foundation::base::{kCFNull, mach_port_t};
 use mach::{kern_return, mach_port, port, traps};
 
-use super::{ffi, IoIterator};
+use super::{ffi, IoIterator, IoObject};
 use crate::{Error, Result};
 
 /// Safe wrapper around the IOKit master port.
@@ -50,6 +50,22 @@ impl IoMasterPort {
             Err(Error::last_os_error().with_ffi("IOServiceGetMatchingServices"))
         }
     }
+
+    /// Returns registry entry located at `path`,
+    /// where `path` is a bytes string ending in a `0x00`,
+    /// for example: `b"IODeviceTree:/product\0"`
+    pub fn get_entry_from_path(&self, path: &[u8]) -> Result<IoObject> {
+        debug_assert!(path.ends_with(b"\0"));
+
+        let entry =
+            unsafe { ffi::IORegistryEntryFromPath(self.0, path.as_ptr() as *const libc::c_char) };
+
+        if entry == port::MACH_PORT_NULL {
+            Err(Error::last_os_error().with_ffi("IORegistryEntryFromPath"))
+        } else {
+            Ok(entry.into())
+        }
+    }
 }
 
 impl Drop for IoMasterPort {
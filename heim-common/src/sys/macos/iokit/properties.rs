@@ -1,5 +1,6 @@
 use core_foundation::base::{CFType, ToVoid};
 use core_foundation::boolean::{CFBoolean, CFBooleanGetTypeID};
+use core_foundation::data::{CFData, CFDataGetTypeID};
 use core_foundation::dictionary::{CFDictionary, CFDictionaryGetTypeID, CFDictionaryRef};
 use core_foundation::number::{CFNumber, CFNumberGetTypeID};
 use core_foundation::string::{CFString, CFStringGetTypeID};
@@ -21,6 +22,9 @@ pub trait DictionaryProps {
 
     /// Get the string value with the `raw_key` key.
     fn get_string(&self, raw_key: &'static str) -> Result<String>;
+
+    /// Get the raw bytes value with the `raw_key` key.
+    fn get_data(&self, raw_key: &'static str) -> Result<Vec<u8>>;
 }
 
 impl DictionaryProps for CFDictionary<CFString, CFType> {
@@ -92,4 +96,20 @@ impl DictionaryProps for CFDictionary<CFString, CFType> {
             // TODO: What should we put instead of the empty second argument?
             .ok_or_else(|| Error::missing_key(raw_key, ""))
     }
+
+    fn get_data(&self, raw_key: &'static str) -> Result<Vec<u8>> {
+        let key = CFString::from_static_string(raw_key);
+
+        self.find(&key)
+            .and_then(|value_ref| {
+                unsafe {
+                    debug_assert!(value_ref.type_of() == CFDataGetTypeID());
+                }
+
+                value_ref.downcast::<CFData>()
+            })
+            .map(|data| data.bytes().to_vec())
+            // TODO: What should we put instead of the empty second argument?
+            .ok_or_else(|| Error::missing_key(raw_key, ""))
+    }
 }
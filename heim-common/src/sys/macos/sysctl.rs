@@ -30,6 +30,50 @@ pub unsafe fn sysctlbyname<T>(key: &[u8]) -> Result<T> {
     }
 }
 
+/// Safer wrapper for `libc::sysctlbyname` for the string values,
+/// ex. `sysctlbyname_string(b"hw.model\0")`.
+///
+/// ## Safety
+///
+/// Same to [sysctlbyname], it is up to caller to provide proper null-terminated C string
+/// for `key` argument.
+pub unsafe fn sysctlbyname_string(key: &[u8]) -> Result<String> {
+    let key = CStr::from_bytes_with_nul_unchecked(key);
+    let mut length = 0;
+
+    // First call is used to get the required buffer size
+    let result = libc::sysctlbyname(
+        key.as_ptr(),
+        ptr::null_mut(),
+        &mut length,
+        ptr::null_mut(),
+        0,
+    );
+    if result != 0 {
+        return Err(Error::last_os_error().with_sysctlbyname(key));
+    }
+
+    let mut value = vec![0u8; length];
+    let result = libc::sysctlbyname(
+        key.as_ptr(),
+        value.as_mut_ptr() as *mut libc::c_void,
+        &mut length,
+        ptr::null_mut(),
+        0,
+    );
+    if result != 0 {
+        return Err(Error::last_os_error().with_sysctlbyname(key));
+    }
+
+    value.truncate(length);
+    // Trailing `\0` is included into the value
+    while value.last() == Some(&0) {
+        let _ = value.pop();
+    }
+
+    Ok(String::from_utf8_lossy(&value).into_owned())
+}
+
 /// Safer wrapper for `libc::syscall`.
 ///
 /// TODO: Not sure why `libc::sysctl` expects `name` to be `*mut`,
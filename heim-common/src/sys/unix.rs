@@ -1,19 +1,148 @@
 //! Unix-specific routines used across `heim` crates.
 
+use std::sync::Mutex;
+
 use crate::{Error, Result};
 
-fn clock_ticks() -> Result<u64> {
-    let result = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+/// Returns `sysconf(3)` value for the `name` parameter.
+///
+/// All parameters used across `heim-*` crates are expected to be positive,
+/// so non-positive value is considered to be an error too.
+pub fn sysconf(name: libc::c_int) -> Result<u64> {
+    let result = unsafe { libc::sysconf(name) };
 
     if result > 0 {
         Ok(result as u64)
     } else {
-        Err(Error::last_os_error().with_sysconf(libc::_SC_CLK_TCK))
+        Err(Error::last_os_error().with_sysconf(name))
     }
 }
 
 lazy_static::lazy_static! {
-    /// Time units in USER_HZ or Jiffies
-    pub static ref CLOCK_TICKS: u64 = clock_ticks()
-        .expect("Unable to determine CPU number of ticks per second");
+    // Only the successfully fetched value is cached,
+    // so the failed call can be retried later
+    static ref CLOCK_TICKS: Mutex<Option<f64>> = Mutex::new(None);
+}
+
+fn cached<F>(cache: &Mutex<Option<f64>>, fetch: F) -> Result<f64>
+where
+    F: FnOnce() -> Result<u64>,
+{
+    // Cached value is a plain number, so it is valid even if some other thread had panicked
+    let mut cache = cache.lock().unwrap_or_else(|e| e.into_inner());
+    match *cache {
+        Some(value) => Ok(value),
+        None => {
+            let value = fetch()? as f64;
+            *cache = Some(value);
+
+            Ok(value)
+        }
+    }
+}
+
+/// Returns time units in `USER_HZ` or Jiffies, used for the procfs values.
+///
+/// Value is fetched via `sysconf(_SC_CLK_TCK)` once and cached for the subsequent calls;
+/// if the call fails (ex. in restricted environments), an error is returned.
+pub fn clock_ticks_cached() -> Result<f64> {
+    cached(&CLOCK_TICKS, || sysconf(libc::_SC_CLK_TCK))
+}
+
+lazy_static::lazy_static! {
+    // Raw OS error code is stored in case of failure,
+    // so the `page_size` caller will get the same error each time
+    static ref PAGE_SIZE: std::result::Result<u64, i32> = sysconf(libc::_SC_PAGESIZE)
+        .map_err(|e| e.raw_os_error().unwrap_or(0));
+}
+
+/// Calls `f` again while it fails with the `EINTR` error.
+///
+/// Blocking syscalls might be interrupted by the signal delivery,
+/// which does not mean that the operation itself had failed.
+pub fn retry_eintr<T, F>(mut f: F) -> Result<T>
+where
+    F: FnMut() -> Result<T>,
+{
+    loop {
+        match f() {
+            Err(e) if e.raw_os_error() == Some(libc::EINTR) => continue,
+            result => return result,
+        }
+    }
+}
+
+/// Returns memory page size in bytes.
+///
+/// Value is fetched via `sysconf(_SC_PAGESIZE)` once and cached for the subsequent calls.
+pub fn page_size() -> Result<u64> {
+    match *PAGE_SIZE {
+        Ok(page_size) => Ok(page_size),
+        Err(code) => Err(Error::from_raw_os_error(code).with_sysconf(libc::_SC_PAGESIZE)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::{cached, clock_ticks_cached, page_size, retry_eintr, sysconf};
+    use crate::Error;
+
+    #[test]
+    fn test_sysconf() {
+        assert!(sysconf(libc::_SC_CLK_TCK).unwrap() > 0);
+        assert_eq!(sysconf(libc::_SC_PAGESIZE).unwrap(), page_size().unwrap());
+
+        let e = sysconf(-1).unwrap_err();
+        assert_eq!(e.raw_os_error(), Some(libc::EINVAL));
+    }
+
+    #[test]
+    fn test_clock_ticks_cached() {
+        let ticks = clock_ticks_cached().unwrap();
+
+        assert_eq!(ticks, sysconf(libc::_SC_CLK_TCK).unwrap() as f64);
+        assert_eq!(clock_ticks_cached().unwrap(), ticks);
+    }
+
+    #[test]
+    fn test_cached_failure() {
+        let cache = Mutex::new(None);
+
+        let e = cached(&cache, || sysconf(-1)).unwrap_err();
+        assert_eq!(e.raw_os_error(), Some(libc::EINVAL));
+        assert!(cache.lock().unwrap().is_none());
+
+        // Failure is not cached, so the next successful call is
+        assert_eq!(cached(&cache, || Ok(250)).unwrap(), 250.0);
+        assert_eq!(cached(&cache, || sysconf(-1)).unwrap(), 250.0);
+    }
+
+    #[test]
+    fn test_retry_eintr() {
+        let mut calls = 0;
+        let result = retry_eintr(|| {
+            calls += 1;
+            if calls < 3 {
+                Err(Error::from_raw_os_error(libc::EINTR))
+            } else {
+                Ok(calls)
+            }
+        });
+
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[test]
+    fn test_retry_eintr_other_error() {
+        let mut calls = 0;
+        let result = retry_eintr::<(), _>(|| {
+            calls += 1;
+            Err(Error::from_raw_os_error(libc::ENOENT))
+        });
+
+        assert_eq!(result.unwrap_err().raw_os_error(), Some(libc::ENOENT));
+        assert_eq!(calls, 1);
+    }
 }
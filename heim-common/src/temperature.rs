@@ -0,0 +1,121 @@
+use std::fmt;
+
+use crate::units::{thermodynamic_temperature, ThermodynamicTemperature};
+
+/// Temperature reading with its optional label and trip points.
+///
+/// Shared across `heim-*` crates, so the temperatures fetched
+/// from the different sources are having the same shape.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Temperature {
+    label: Option<String>,
+    current: ThermodynamicTemperature,
+    high: Option<ThermodynamicTemperature>,
+    critical: Option<ThermodynamicTemperature>,
+}
+
+impl Temperature {
+    /// Creates new temperature reading with the `current` value.
+    pub fn new(current: ThermodynamicTemperature) -> Temperature {
+        Temperature {
+            label: None,
+            current,
+            high: None,
+            critical: None,
+        }
+    }
+
+    /// Sets the temperature label.
+    pub fn with_label(mut self, label: Option<String>) -> Temperature {
+        self.label = label;
+        self
+    }
+
+    /// Sets the high trip point.
+    pub fn with_high(mut self, high: Option<ThermodynamicTemperature>) -> Temperature {
+        self.high = high;
+        self
+    }
+
+    /// Sets the critical trip point.
+    pub fn with_critical(mut self, critical: Option<ThermodynamicTemperature>) -> Temperature {
+        self.critical = critical;
+        self
+    }
+
+    /// Returns temperature label if available.
+    #[allow(clippy::option_as_ref_deref)] // >= 1.40.0
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_ref().map(|s| s.as_str())
+    }
+
+    /// Returns current temperature.
+    pub fn current(&self) -> ThermodynamicTemperature {
+        self.current
+    }
+
+    /// Returns high trip point if available.
+    pub fn high(&self) -> Option<ThermodynamicTemperature> {
+        self.high
+    }
+
+    /// Returns critical trip point if available.
+    pub fn critical(&self) -> Option<ThermodynamicTemperature> {
+        self.critical
+    }
+}
+
+fn celsius(value: ThermodynamicTemperature) -> f32 {
+    value.get::<thermodynamic_temperature::degree_celsius>()
+}
+
+/// Formats temperature in the `lm-sensors` fashion, ex.
+/// `Core 0: 45.0 °C (high = 80.0 °C, crit = 100.0 °C)`.
+impl fmt::Display for Temperature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(label) = self.label() {
+            write!(f, "{}: ", label)?;
+        }
+        write!(f, "{:.1} °C", celsius(self.current))?;
+
+        match (self.high, self.critical) {
+            (Some(high), Some(critical)) => write!(
+                f,
+                " (high = {:.1} °C, crit = {:.1} °C)",
+                celsius(high),
+                celsius(critical)
+            ),
+            (Some(high), None) => write!(f, " (high = {:.1} °C)", celsius(high)),
+            (None, Some(critical)) => write!(f, " (crit = {:.1} °C)", celsius(critical)),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Temperature;
+    use crate::units::{thermodynamic_temperature, ThermodynamicTemperature};
+
+    fn celsius(value: f32) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<thermodynamic_temperature::degree_celsius>(value)
+    }
+
+    #[test]
+    fn test_display() {
+        let temperature = Temperature::new(celsius(45.0));
+        assert_eq!(temperature.to_string(), "45.0 °C");
+
+        let temperature = Temperature::new(celsius(45.0))
+            .with_label(Some("Core 0".to_string()))
+            .with_high(Some(celsius(80.0)))
+            .with_critical(Some(celsius(100.0)));
+        assert_eq!(
+            temperature.to_string(),
+            "Core 0: 45.0 °C (high = 80.0 °C, crit = 100.0 °C)"
+        );
+
+        let temperature = Temperature::new(celsius(45.0)).with_critical(Some(celsius(100.0)));
+        assert_eq!(temperature.to_string(), "45.0 °C (crit = 100.0 °C)");
+    }
+}
@@ -0,0 +1,116 @@
+//! Timeouts for the `heim` futures and streams.
+//!
+//! Some system information sources might hang for a long time,
+//! ex. reading `/proc` file for a stuck NFS mount or querying the disk counters
+//! of a device with misbehaving driver; these functions are helping to bound the waiting time.
+//!
+//! ```rust
+//! # use std::time::Duration;
+//! # use heim_common::prelude::*;
+//! # use heim_common::with_timeout;
+//! # fn main() -> Result<()> {
+//! # futures_executor::block_on(async {
+//! let value = with_timeout(future::pending::<Result<u64>>(), Duration::from_millis(10)).await;
+//! assert!(value.unwrap_err().is_timed_out());
+//! # Ok(())
+//! # })
+//! # }
+//! ```
+
+use std::io;
+use std::time::Duration;
+
+use futures_timer::Delay;
+
+use crate::prelude::{future, stream, Future, Stream, StreamExt};
+use crate::{Error, Result};
+
+fn timed_out(duration: Duration) -> Error {
+    Error::from(io::Error::from(io::ErrorKind::TimedOut))
+        .with_message(format!("Operation timed out after {:?}", duration))
+}
+
+async fn timeout<F>(future: F, duration: Duration) -> Result<F::Output>
+where
+    F: Future,
+{
+    let delay = Delay::new(duration);
+    pin_utils::pin_mut!(future, delay);
+
+    match future::select(future, delay).await {
+        future::Either::Left((output, _)) => Ok(output),
+        future::Either::Right(_) => Err(timed_out(duration)),
+    }
+}
+
+/// Resolves to the `future` output, or fails with the [`io::ErrorKind::TimedOut`] error
+/// if it was not completed in the `duration` period.
+///
+/// Inner future is dropped on timeout; note that it does not stop the blocking operations
+/// which were already started in a separate thread, but the caller will not wait for them anymore.
+///
+/// [`io::ErrorKind::TimedOut`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.TimedOut
+pub async fn with_timeout<F, T>(future: F, duration: Duration) -> Result<T>
+where
+    F: Future<Output = Result<T>>,
+{
+    timeout(future, duration).await?
+}
+
+/// Yields the `stream` items, failing with the [`io::ErrorKind::TimedOut`] error
+/// if any of the items was not received in the `duration` period.
+///
+/// Timeout is applied to each item separately;
+/// after the timeout error is yielded, stream is terminated.
+///
+/// [`io::ErrorKind::TimedOut`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.TimedOut
+pub fn stream_with_timeout<S, T>(stream: S, duration: Duration) -> impl Stream<Item = Result<T>>
+where
+    S: Stream<Item = Result<T>>,
+{
+    stream::unfold(Some(Box::pin(stream)), move |state| async move {
+        let mut inner = state?;
+        match timeout(inner.next(), duration).await {
+            Ok(Some(item)) => Some((item, Some(inner))),
+            Ok(None) => None,
+            Err(e) => Some((Err(e), None)),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures_executor::block_on;
+
+    use super::{stream_with_timeout, with_timeout};
+    use crate::prelude::*;
+
+    #[test]
+    fn test_with_timeout() {
+        let value = block_on(with_timeout(
+            future::ok::<u64, Error>(42),
+            Duration::from_secs(1),
+        ));
+        assert_eq!(value.unwrap(), 42);
+
+        let value = block_on(with_timeout(
+            future::pending::<Result<u64>>(),
+            Duration::from_millis(10),
+        ));
+        assert!(value.unwrap_err().is_timed_out());
+    }
+
+    #[test]
+    fn test_stream_with_timeout() {
+        let inner = stream::iter(vec![Ok(1), Ok(2)]).chain(stream::pending::<Result<u64>>());
+        let items =
+            block_on(stream_with_timeout(inner, Duration::from_millis(10)).collect::<Vec<_>>());
+
+        assert_eq!(items.len(), 3);
+        assert_eq!(*items[0].as_ref().unwrap(), 1);
+        assert_eq!(*items[1].as_ref().unwrap(), 2);
+        assert!(items[2].as_ref().unwrap_err().is_timed_out());
+    }
+}
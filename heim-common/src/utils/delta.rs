@@ -0,0 +1,146 @@
+//! Helpers for the rate calculation between two samples of the monotonic counters.
+//!
+//! Used by the `rate_since` and `delta` methods of the `IoCounters` structs in the `heim-*` crates.
+
+use crate::units::{information, information_rate, time, Information, InformationRate, Time};
+
+/// Returns difference between two samples of the monotonic counter.
+///
+/// If `current` value is less than `previous` one, counter was reset or wrapped around,
+/// in that case delta is clamped to zero.
+pub fn counter_delta(current: u64, previous: u64) -> u64 {
+    current.saturating_sub(previous)
+}
+
+/// Returns difference between two samples of the monotonic counter,
+/// treating any decrease as a counter reset.
+///
+/// If `current` value is less than `previous` one, counter was reset (or wrapped around)
+/// and started counting from zero again, so the `current` value is returned as is,
+/// instead of zero or a huge wrapped difference.
+pub fn counter_delta_since_reset(current: u64, previous: u64) -> u64 {
+    if current < previous {
+        current
+    } else {
+        current - previous
+    }
+}
+
+/// Returns information difference between two samples of the monotonic information counter,
+/// treating any decrease as a counter reset, same as [counter_delta_since_reset] does.
+///
+/// [counter_delta_since_reset]: fn.counter_delta_since_reset.html
+pub fn information_delta_since_reset(current: Information, previous: Information) -> Information {
+    Information::new::<information::byte>(counter_delta_since_reset(
+        current.get::<information::byte>(),
+        previous.get::<information::byte>(),
+    ))
+}
+
+/// Returns per-second rate for the already calculated counter `delta` during the `elapsed` time.
+///
+/// Zero is returned for the zero or negative `elapsed` time.
+pub fn delta_per_second(delta: u64, elapsed: Time) -> f64 {
+    let seconds = elapsed.get::<time::second>();
+    if seconds <= 0.0 {
+        return 0.0;
+    }
+
+    delta as f64 / seconds
+}
+
+/// Returns information rate for the already calculated information `delta`
+/// during the `elapsed` time.
+///
+/// Zero rate is returned for the zero or negative `elapsed` time.
+pub fn information_delta_rate(delta: Information, elapsed: Time) -> InformationRate {
+    let bytes = delta_per_second(delta.get::<information::byte>(), elapsed);
+
+    InformationRate::new::<information_rate::byte_per_second>(bytes)
+}
+
+/// Returns per-second rate for the monotonic counter change during the `elapsed` time.
+///
+/// Zero is returned for the zero or negative `elapsed` time.
+pub fn per_second(current: u64, previous: u64, elapsed: Time) -> f64 {
+    delta_per_second(counter_delta(current, previous), elapsed)
+}
+
+/// Returns information rate for the monotonic information counter change
+/// during the `elapsed` time.
+pub fn information_rate(
+    current: Information,
+    previous: Information,
+    elapsed: Time,
+) -> InformationRate {
+    let delta = counter_delta(
+        current.get::<information::byte>(),
+        previous.get::<information::byte>(),
+    );
+
+    information_delta_rate(Information::new::<information::byte>(delta), elapsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counter_delta() {
+        assert_eq!(counter_delta(150, 100), 50);
+        assert_eq!(counter_delta(100, 100), 0);
+        // Counter was reset
+        assert_eq!(counter_delta(10, 100), 0);
+    }
+
+    #[test]
+    fn test_counter_delta_since_reset() {
+        assert_eq!(counter_delta_since_reset(150, 100), 50);
+        assert_eq!(counter_delta_since_reset(100, 100), 0);
+        // Counter was reset and counted up to 10 since then
+        assert_eq!(counter_delta_since_reset(10, 100), 10);
+        assert_eq!(counter_delta_since_reset(0, u64::MAX), 0);
+    }
+
+    #[test]
+    fn test_information_delta_since_reset() {
+        let delta = information_delta_since_reset(
+            Information::new::<information::byte>(10),
+            Information::new::<information::byte>(100),
+        );
+
+        assert_eq!(delta.get::<information::byte>(), 10);
+    }
+
+    #[test]
+    fn test_delta_per_second() {
+        assert_eq!(delta_per_second(200, Time::new::<time::second>(2.0)), 100.0);
+        assert_eq!(delta_per_second(200, Time::new::<time::second>(0.0)), 0.0);
+        assert_eq!(delta_per_second(200, Time::new::<time::second>(-1.0)), 0.0);
+
+        let rate = information_delta_rate(
+            Information::new::<information::kibibyte>(2),
+            Time::new::<time::second>(2.0),
+        );
+        assert_eq!(rate.get::<information_rate::byte_per_second>(), 1024.0);
+    }
+
+    #[test]
+    fn test_per_second() {
+        let elapsed = Time::new::<time::second>(2.0);
+        assert_eq!(per_second(300, 100, elapsed), 100.0);
+        assert_eq!(per_second(100, 300, elapsed), 0.0);
+        assert_eq!(per_second(300, 100, Time::new::<time::second>(0.0)), 0.0);
+    }
+
+    #[test]
+    fn test_information_rate() {
+        let rate = information_rate(
+            Information::new::<information::kibibyte>(3),
+            Information::new::<information::kibibyte>(1),
+            Time::new::<time::second>(2.0),
+        );
+
+        assert_eq!(rate.get::<information_rate::byte_per_second>(), 1024.0);
+    }
+}
@@ -1,4 +1,5 @@
 //! Internal to `heim-*` crates utilities for easier development process.
 
+pub mod delta;
 pub mod iter;
 pub mod stream;
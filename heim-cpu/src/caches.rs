@@ -0,0 +1,84 @@
+use heim_common::prelude::*;
+use heim_common::units::Information;
+
+use crate::sys;
+
+/// CPU cache type.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_crate::Serialize),
+    serde(crate = "serde_crate")
+)]
+#[non_exhaustive]
+pub enum CacheKind {
+    /// Data cache.
+    Data,
+    /// Instruction cache.
+    Instruction,
+    /// Unified (data and instruction) cache.
+    Unified,
+}
+
+/// CPU cache information.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_crate::Serialize),
+    serde(crate = "serde_crate")
+)]
+pub struct Cache {
+    pub(crate) level: u8,
+    pub(crate) kind: CacheKind,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "heim_common::serialize::information")
+    )]
+    pub(crate) size: Information,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "heim_common::serialize::option_information")
+    )]
+    pub(crate) line_size: Option<Information>,
+}
+
+impl Cache {
+    /// Returns cache level, ex. `1` for the L1 cache.
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// Returns cache type.
+    pub fn kind(&self) -> CacheKind {
+        self.kind
+    }
+
+    /// Returns cache size.
+    pub fn size(&self) -> Information {
+        self.size
+    }
+
+    /// Returns cache line size, if available.
+    pub fn line_size(&self) -> Option<Information> {
+        self.line_size
+    }
+}
+
+/// Returns information about the CPU [caches].
+///
+/// Caches are described as they are seen by the first logical CPU,
+/// therefore, for example, only one L1 data cache will be returned
+/// for a multi-core system.
+///
+/// ## Compatibility
+///
+/// * For Linux caches are read from the `/sys/devices/system/cpu/cpu0/cache/` directory;
+///   if it is missing, x86 CPUs are queried via the `CPUID` instruction (leaf 4)
+/// * For macOS `hw.l1dcachesize`, `hw.l1icachesize`, `hw.l2cachesize`
+///   and `hw.l3cachesize` sysctls are used
+/// * For Windows caches are fetched via the `GetLogicalProcessorInformationEx` function
+///
+/// [caches]: ./struct.Cache.html
+pub async fn caches() -> Result<Vec<Cache>> {
+    sys::caches().await
+}
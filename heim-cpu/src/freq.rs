@@ -1,7 +1,9 @@
 use std::fmt;
+use std::time::Duration;
 
 use heim_common::prelude::*;
 use heim_common::units::Frequency;
+use heim_runtime as rt;
 
 use crate::sys;
 
@@ -13,11 +15,19 @@ wrap!(CpuFrequency, sys::CpuFrequency);
 impl CpuFrequency {
     /// Current CPU frequency.
     ///
+    /// ## Returns
+    ///
+    /// Returns `None` if value can't be determined.
+    ///
     /// ## Compatibility
     ///
     /// On Linux it returns the real-time value, on all other platforms
     /// it represents the nominal "fixed" value.
-    pub fn current(&self) -> Frequency {
+    ///
+    /// For macOS running on Apple Silicon (M-series) chips `None` is returned,
+    /// as these CPUs are not reporting their frequencies via `hw.cpufrequency*` sysctls;
+    /// same applies to the `min` and `max` values.
+    pub fn current(&self) -> Option<Frequency> {
         self.as_ref().current()
     }
 
@@ -26,6 +36,11 @@ impl CpuFrequency {
     /// ## Returns
     ///
     /// Returns `None` if value can't be determined.
+    ///
+    /// ## Compatibility
+    ///
+    /// On Linux it is the `scaling_min_freq` sysfs value,
+    /// or the hardware `cpuinfo_min_freq` one if the former is missing.
     pub fn min(&self) -> Option<Frequency> {
         self.as_ref().min()
     }
@@ -35,9 +50,42 @@ impl CpuFrequency {
     /// ## Returns
     ///
     /// Returns `None` if value can't be determined.
+    ///
+    /// ## Compatibility
+    ///
+    /// On Linux it is the `scaling_max_freq` sysfs value,
+    /// or the hardware `cpuinfo_max_freq` one if the former is missing.
     pub fn max(&self) -> Option<Frequency> {
         self.as_ref().max()
     }
+
+    /// Base (nominal) CPU frequency, as advertised by the vendor.
+    ///
+    /// Unlike the [`current`] value, it is not affected by the CPU throttling or boosting,
+    /// which makes it usable for the "percent of nominal" calculations.
+    ///
+    /// ## Returns
+    ///
+    /// Returns `None` if value can't be determined.
+    ///
+    /// ## Compatibility
+    ///
+    /// On Linux the `base_frequency` sysfs value is used where present (Intel P-state driver),
+    /// next the frequency from the CPU brand string (ex. `Intel(R) Core(TM) i7-8550U CPU @ 1.80GHz`)
+    /// is tried and the `cpuinfo_max_freq` sysfs value is used as a last resort;
+    /// note that the latter one includes the boost frequencies for some CPUs.
+    ///
+    /// On macOS it is the same nominal value as the [`current`] one,
+    /// and `None` is returned for Apple Silicon (M-series) chips.
+    ///
+    /// On Windows it is the same rated value as the [`max`] one (`MaxMhz` value
+    /// of the `CallNtPowerInformation` function), which does not include the boost frequencies.
+    ///
+    /// [`current`]: #method.current
+    /// [`max`]: #method.max
+    pub fn base(&self) -> Option<Frequency> {
+        self.as_ref().base()
+    }
 }
 
 impl fmt::Debug for CpuFrequency {
@@ -46,10 +94,30 @@ impl fmt::Debug for CpuFrequency {
             .field("current", &self.current())
             .field("min", &self.min())
             .field("max", &self.max())
+            .field("base", &self.base())
             .finish()
     }
 }
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde_crate::Serialize for CpuFrequency {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde_crate::Serializer,
+    {
+        use heim_common::serialize::Quantity;
+        use serde_crate::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("CpuFrequency", 4)?;
+        state.serialize_field("current", &Quantity(&self.current()))?;
+        state.serialize_field("min", &Quantity(&self.min()))?;
+        state.serialize_field("max", &Quantity(&self.max()))?;
+        state.serialize_field("base", &Quantity(&self.base()))?;
+        state.end()
+    }
+}
+
 /// Returns [CpuFrequency].
 ///
 /// ## Compatibility
@@ -62,3 +130,15 @@ impl fmt::Debug for CpuFrequency {
 pub async fn frequency() -> Result<CpuFrequency> {
     sys::frequency().await.map(Into::into)
 }
+
+/// Returns a stream which yields the [CpuFrequency] every `interval`.
+///
+/// First value is yielded immediately, and the stream never ends.
+/// Failed fetch is yielded as an error without terminating the stream.
+///
+/// [CpuFrequency]: ./struct.CpuFrequency.html
+pub fn frequency_watch(interval: Duration) -> impl Stream<Item = Result<CpuFrequency>> {
+    stream::once(future::ready(()))
+        .chain(rt::time::interval(interval))
+        .then(|_| frequency())
+}
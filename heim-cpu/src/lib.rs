@@ -33,14 +33,32 @@
 pub mod os;
 mod sys;
 
+mod caches;
 mod count;
 mod freq;
 mod stats;
+#[cfg(feature = "sensors")]
+mod temperatures;
 mod times;
 mod usage;
 
+pub use self::caches::*;
 pub use self::count::*;
 pub use self::freq::*;
 pub use self::stats::*;
+#[cfg(feature = "sensors")]
+pub use self::temperatures::*;
 pub use self::times::*;
 pub use self::usage::*;
+
+/// Re-exports of the commonly used CPU types and the measurement units for them.
+///
+/// ```rust
+/// use heim_cpu::prelude::*;
+/// ```
+pub mod prelude {
+    pub use crate::{Cache, CacheKind, CpuFrequency, CpuStats, CpuTime, CpuUsage};
+    pub use heim_common::units::{
+        frequency, information, ratio, time, Frequency, Information, Ratio, Time,
+    };
+}
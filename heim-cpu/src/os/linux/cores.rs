@@ -0,0 +1,58 @@
+use heim_common::prelude::*;
+use heim_common::units::Frequency;
+
+use crate::sys;
+
+/// Core type of the heterogeneous CPU, ex. ARM big.LITTLE or Intel hybrid CPUs.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum CoreKind {
+    /// High performance core ("big" or "P-core").
+    Performance,
+    /// Power efficient core ("LITTLE" or "E-core").
+    Efficiency,
+}
+
+/// Logical CPU information.
+///
+/// See [cores] function.
+///
+/// [cores]: ./fn.cores.html
+#[derive(Debug, Clone)]
+pub struct Core {
+    pub(crate) id: u32,
+    pub(crate) max_frequency: Option<Frequency>,
+    pub(crate) kind: Option<CoreKind>,
+}
+
+impl Core {
+    /// Returns logical CPU number, ex. `3` for the `/sys/devices/system/cpu/cpu3`.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns maximal frequency for this logical CPU, if available.
+    pub fn max_frequency(&self) -> Option<Frequency> {
+        self.max_frequency
+    }
+
+    /// Returns core type hint, if it can be inferred.
+    ///
+    /// ## Returns
+    ///
+    /// Returns `None` for the homogeneous CPUs,
+    /// or if the required information is not available.
+    pub fn kind(&self) -> Option<CoreKind> {
+        self.kind
+    }
+}
+
+/// Returns information about each logical CPU in the system, ordered by the CPU number.
+///
+/// Core type is taken from the `cpu_core` and `cpu_atom` PMU devices for Intel hybrid CPUs,
+/// otherwise it is inferred from the `cpu_capacity` sysfs values (available for ARM CPUs)
+/// or from the `cpuinfo_max_freq` values, if the capacities are missing.
+/// Values are required to be clearly split into two clusters, otherwise core type is unknown.
+pub async fn cores() -> Result<Vec<Core>> {
+    sys::cores().await
+}
@@ -4,7 +4,13 @@ use crate::{sys, CpuFrequency};
 
 /// Returns a stream over the per-[CPU frequencies] information.
 ///
-/// Order of the stream is constant.
+/// Frequencies are read from the `/sys/devices/system/cpu/cpuN/cpufreq/` directories
+/// and yielded in the logical CPU index order.
+/// CPUs which frequencies are not readable due to the insufficient permissions are skipped.
+///
+/// If the `cpufreq` sysfs tree is missing (ex. for some VMs), the `cpu MHz` values
+/// from the `/proc/cpuinfo` file are used for the current frequencies,
+/// while the `min` and `max` ones are `None`.
 ///
 /// [CPU frequencies]: ../../struct.CpuFrequency.html
 pub fn frequencies() -> impl Stream<Item = Result<CpuFrequency>> {
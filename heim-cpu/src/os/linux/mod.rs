@@ -1,9 +1,13 @@
 //! Linux-specific extensions.
 
+mod cores;
 mod freq;
+mod quota;
 mod stats;
 mod times;
 
+pub use self::cores::*;
 pub use self::freq::*;
+pub use self::quota::*;
 pub use self::stats::*;
 pub use self::times::*;
@@ -0,0 +1,24 @@
+use heim_common::prelude::*;
+
+use crate::sys;
+
+/// Returns CPU quota of the current process cgroup as an amount of the effective CPU cores,
+/// ex. `1.5` for the `150000 100000` cgroup v2 `cpu.max` limit.
+///
+/// Both cgroup v1 (`cpu.cfs_quota_us` and `cpu.cfs_period_us` files)
+/// and cgroup v2 (`cpu.max` file) hierarchies are supported,
+/// mounted hierarchy version is detected via `/proc/self/mountinfo`.
+/// Since limits are hierarchical, the smallest one among the process cgroup
+/// and all its ancestors is returned.
+///
+/// Unlike the [logical_count], this value reflects how many CPUs can be actually used
+/// by the process running in the container (ex. in Kubernetes).
+///
+/// ## Returns
+///
+/// `Ok(None)` is returned if there is no CPU limit or cgroups are not available.
+///
+/// [logical_count]: ../../fn.logical_count.html
+pub async fn quota() -> Result<Option<f64>> {
+    sys::quota().await
+}
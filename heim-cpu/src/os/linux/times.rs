@@ -4,6 +4,17 @@ use heim_common::units::Time;
 ///
 /// [CpuTime]: ../../struct.CpuTime.html
 pub trait CpuTimeExt {
+    /// Returns logical CPU index this time belongs to, same as the kernel numbering.
+    ///
+    /// `None` is returned for the cumulative [time] value.
+    ///
+    /// Offline CPUs are not listed by the kernel at all,
+    /// so the indexes in the [times] stream might have gaps.
+    ///
+    /// [time]: ../../fn.time.html
+    /// [times]: ../../fn.times.html
+    fn cpu(&self) -> Option<usize>;
+
     /// Returns time spent by niced (prioritized) processes executing in user mode,
     /// this also includes [guest_nice] time.
     ///
@@ -37,10 +48,68 @@ pub trait CpuTimeExt {
     ///
     /// Available for Linux 3.2.0+, older versions always returns `None`.
     fn guest_nice(&self) -> Option<Time>;
+
+    /// Returns [user] time in clock ticks (`USER_HZ` units), as reported by the kernel.
+    ///
+    /// Contrary to the `Time`-typed getters, raw tick counts are not affected
+    /// by the floating-point conversion precision loss on long-running hosts.
+    /// Divide them by the `sysconf(_SC_CLK_TCK)` value to get time in seconds.
+    ///
+    /// [user]: ../../struct.CpuTime.html#method.user
+    fn user_ticks(&self) -> u64;
+
+    /// Returns [nice] time in clock ticks.
+    ///
+    /// [nice]: #tymethod.nice
+    fn nice_ticks(&self) -> u64;
+
+    /// Returns [system] time in clock ticks.
+    ///
+    /// [system]: ../../struct.CpuTime.html#method.system
+    fn system_ticks(&self) -> u64;
+
+    /// Returns [idle] time in clock ticks.
+    ///
+    /// [idle]: ../../struct.CpuTime.html#method.idle
+    fn idle_ticks(&self) -> u64;
+
+    /// Returns [io_wait] time in clock ticks.
+    ///
+    /// [io_wait]: #tymethod.io_wait
+    fn io_wait_ticks(&self) -> u64;
+
+    /// Returns [irq] time in clock ticks.
+    ///
+    /// [irq]: #tymethod.irq
+    fn irq_ticks(&self) -> u64;
+
+    /// Returns [soft_irq] time in clock ticks.
+    ///
+    /// [soft_irq]: #tymethod.soft_irq
+    fn soft_irq_ticks(&self) -> u64;
+
+    /// Returns [steal] time in clock ticks.
+    ///
+    /// [steal]: #tymethod.steal
+    fn steal_ticks(&self) -> u64;
+
+    /// Returns [guest] time in clock ticks.
+    ///
+    /// [guest]: #tymethod.guest
+    fn guest_ticks(&self) -> Option<u64>;
+
+    /// Returns [guest_nice] time in clock ticks.
+    ///
+    /// [guest_nice]: #tymethod.guest_nice
+    fn guest_nice_ticks(&self) -> Option<u64>;
 }
 
 #[cfg(target_os = "linux")]
 impl CpuTimeExt for crate::CpuTime {
+    fn cpu(&self) -> Option<usize> {
+        self.as_ref().cpu()
+    }
+
     fn nice(&self) -> Time {
         self.as_ref().nice()
     }
@@ -68,4 +137,44 @@ impl CpuTimeExt for crate::CpuTime {
     fn guest_nice(&self) -> Option<Time> {
         self.as_ref().guest_nice()
     }
+
+    fn user_ticks(&self) -> u64 {
+        self.as_ref().user_ticks()
+    }
+
+    fn nice_ticks(&self) -> u64 {
+        self.as_ref().nice_ticks()
+    }
+
+    fn system_ticks(&self) -> u64 {
+        self.as_ref().system_ticks()
+    }
+
+    fn idle_ticks(&self) -> u64 {
+        self.as_ref().idle_ticks()
+    }
+
+    fn io_wait_ticks(&self) -> u64 {
+        self.as_ref().io_wait_ticks()
+    }
+
+    fn irq_ticks(&self) -> u64 {
+        self.as_ref().irq_ticks()
+    }
+
+    fn soft_irq_ticks(&self) -> u64 {
+        self.as_ref().soft_irq_ticks()
+    }
+
+    fn steal_ticks(&self) -> u64 {
+        self.as_ref().steal_ticks()
+    }
+
+    fn guest_ticks(&self) -> Option<u64> {
+        self.as_ref().guest_ticks()
+    }
+
+    fn guest_nice_ticks(&self) -> Option<u64> {
+        self.as_ref().guest_nice_ticks()
+    }
 }
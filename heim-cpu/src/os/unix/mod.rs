@@ -1,7 +1,10 @@
 //! Linux-specific extensions.
 
+use std::time::Duration;
+
+use heim_common::prelude::*;
 use heim_common::units::Ratio;
-use heim_common::Result;
+use heim_runtime as rt;
 
 use crate::sys;
 
@@ -9,6 +12,11 @@ use crate::sys;
 ///
 /// The load represents the processes which are in a runnable state,
 /// either using the CPU or waiting to use the CPU (e.g. waiting for disk I/O).
+///
+/// ## Compatibility
+///
+/// On Linux values are read from the `/proc/loadavg` file,
+/// for other platforms `getloadavg(3)` is used.
 pub async fn loadavg() -> Result<(Ratio, Ratio, Ratio)> {
     cfg_if::cfg_if! {
         if #[cfg(unix)] {
@@ -18,3 +26,15 @@ pub async fn loadavg() -> Result<(Ratio, Ratio, Ratio)> {
         }
     }
 }
+
+/// Returns a stream which yields the [average system load] every `interval`.
+///
+/// First value is yielded immediately, and the stream never ends.
+/// Failed fetch is yielded as an error without terminating the stream.
+///
+/// [average system load]: ./fn.loadavg.html
+pub fn loadavg_watch(interval: Duration) -> impl Stream<Item = Result<(Ratio, Ratio, Ratio)>> {
+    stream::once(future::ready(()))
+        .chain(rt::time::interval(interval))
+        .then(|_| loadavg())
+}
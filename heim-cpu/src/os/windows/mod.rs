@@ -1,5 +1,7 @@
 //! Windows-specific extensions.
 
 mod stats;
+mod times;
 
 pub use self::stats::*;
+pub use self::times::*;
@@ -0,0 +1,29 @@
+use heim_common::units::Time;
+
+/// Windows-specific extension for [CpuTime].
+///
+/// Methods are named same as in the Linux [CpuTimeExt] trait,
+/// so the cross-platform code can read the interrupts time uniformly.
+///
+/// [CpuTime]: ../../struct.CpuTime.html
+/// [CpuTimeExt]: ../linux/trait.CpuTimeExt.html
+pub trait CpuTimeExt {
+    /// Returns time spent for servicing hardware interrupts.
+    fn irq(&self) -> Time;
+
+    /// Returns time spent for servicing [Deferred Procedure Calls].
+    ///
+    /// [Deferred Procedure Calls]: https://en.wikipedia.org/wiki/Deferred_Procedure_Call
+    fn soft_irq(&self) -> Time;
+}
+
+#[cfg(target_os = "windows")]
+impl CpuTimeExt for crate::CpuTime {
+    fn irq(&self) -> Time {
+        self.as_ref().interrupt()
+    }
+
+    fn soft_irq(&self) -> Time {
+        self.as_ref().dpc()
+    }
+}
@@ -32,6 +32,22 @@ impl fmt::Debug for CpuStats {
     }
 }
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde_crate::Serialize for CpuStats {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde_crate::Serializer,
+    {
+        use serde_crate::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("CpuStats", 2)?;
+        state.serialize_field("ctx_switches", &self.ctx_switches())?;
+        state.serialize_field("interrupts", &self.interrupts())?;
+        state.end()
+    }
+}
+
 /// Returns [CpuStats] information.
 ///
 /// [CpuStats]: ./struct.CpuStats.html
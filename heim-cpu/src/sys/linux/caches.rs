@@ -0,0 +1,155 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use heim_common::prelude::*;
+use heim_common::sys::linux::read_value;
+use heim_common::units::{information, Information};
+use heim_runtime as rt;
+
+use crate::{Cache, CacheKind};
+
+// Sizes are represented as `32K`, `8192K` and so on.
+fn parse_size(value: &str) -> Result<Information> {
+    let value = value.trim();
+    let (digits, multiplier) = match value.as_bytes().last() {
+        Some(b'K') => (&value[..value.len() - 1], 1024),
+        Some(b'M') => (&value[..value.len() - 1], 1024 * 1024),
+        Some(b'G') => (&value[..value.len() - 1], 1024 * 1024 * 1024),
+        _ => (value, 1),
+    };
+    let bytes = digits.parse::<u64>()?;
+
+    Ok(Information::new::<information::byte>(bytes * multiplier))
+}
+
+fn read_cache(root: &Path) -> Result<Option<Cache>> {
+    let kind = match read_value(root.join("type"))?.as_deref() {
+        Some("Data") => CacheKind::Data,
+        Some("Instruction") => CacheKind::Instruction,
+        Some("Unified") => CacheKind::Unified,
+        _ => return Ok(None),
+    };
+    let level = match read_value(root.join("level"))? {
+        Some(level) => level.parse::<u8>()?,
+        None => return Ok(None),
+    };
+    let size = match read_value(root.join("size"))? {
+        Some(size) => parse_size(&size)?,
+        None => return Ok(None),
+    };
+    let line_size = match read_value(root.join("coherency_line_size"))? {
+        Some(line_size) => Some(parse_size(&line_size)?),
+        None => None,
+    };
+
+    Ok(Some(Cache {
+        level,
+        kind,
+        size,
+        line_size,
+    }))
+}
+
+fn sysfs_caches() -> Result<Vec<Cache>> {
+    let root = rt::linux::sysfs_root().join("devices/system/cpu/cpu0/cache");
+    let entries = match fs::read_dir(&root) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(Error::from(e).with_file(root)),
+    };
+
+    let mut indexes = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name();
+        let index = name
+            .to_str()
+            .and_then(|name| name.strip_prefix("index"))
+            .and_then(|index| index.parse::<u32>().ok());
+        if let Some(index) = index {
+            indexes.push((index, entry.path()));
+        }
+    }
+    indexes.sort_by_key(|(index, _)| *index);
+
+    let mut caches = Vec::with_capacity(indexes.len());
+    for (_, path) in indexes {
+        if let Some(cache) = read_cache(&path)? {
+            caches.push(cache);
+        }
+    }
+
+    Ok(caches)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn cpuid_caches() -> Vec<Cache> {
+    use raw_cpuid::{CacheType, CpuId};
+
+    let parameters = match CpuId::new().get_cache_parameters() {
+        Some(parameters) => parameters,
+        None => return vec![],
+    };
+
+    parameters
+        .filter_map(|cache| {
+            let kind = match cache.cache_type() {
+                CacheType::Data => CacheKind::Data,
+                CacheType::Instruction => CacheKind::Instruction,
+                CacheType::Unified => CacheKind::Unified,
+                _ => return None,
+            };
+            let line_size = cache.coherency_line_size() as u64;
+            let size = cache.associativity() as u64
+                * cache.physical_line_partitions() as u64
+                * line_size
+                * cache.sets() as u64;
+
+            Some(Cache {
+                level: cache.level(),
+                kind,
+                size: Information::new::<information::byte>(size),
+                line_size: Some(Information::new::<information::byte>(line_size)),
+            })
+        })
+        .collect()
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+fn cpuid_caches() -> Vec<Cache> {
+    vec![]
+}
+
+pub async fn caches() -> Result<Vec<Cache>> {
+    rt::spawn_blocking(|| {
+        let caches = sysfs_caches()?;
+        if caches.is_empty() {
+            Ok(cpuid_caches())
+        } else {
+            Ok(caches)
+        }
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use heim_common::units::information;
+
+    use super::parse_size;
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(
+            parse_size("32K").unwrap().get::<information::byte>(),
+            32 * 1024
+        );
+        assert_eq!(
+            parse_size("8192K\n").unwrap().get::<information::byte>(),
+            8192 * 1024
+        );
+        assert_eq!(parse_size("64").unwrap().get::<information::byte>(), 64);
+        assert!(parse_size("K").is_err());
+    }
+}
@@ -0,0 +1,220 @@
+use std::fs;
+use std::path::Path;
+
+use heim_common::prelude::*;
+use heim_common::sys::linux::read_value;
+use heim_common::units::{frequency, Frequency};
+use heim_runtime as rt;
+
+use crate::os::linux::{Core, CoreKind};
+
+fn read_number(path: &Path) -> Result<Option<u64>> {
+    match read_value(path)? {
+        Some(value) => Ok(Some(value.parse()?)),
+        None => Ok(None),
+    }
+}
+
+/// Values are considered to belong to the different core kinds only if they are split
+/// into two clusters by a gap of at least this share of the highest value (in percents).
+///
+/// Preferred cores of the homogeneous CPUs (ex. Intel Turbo Boost Max 3.0 or AMD CPPC)
+/// have their maximal frequencies differ by a few percents only.
+const HYBRID_GAP_PERCENT: u64 = 15;
+
+/// Cores above the widest gap between the values are considered to be the performance ones.
+///
+/// If values are missing for some cores or they are not clearly split into the clusters,
+/// nothing can be inferred.
+fn classify(values: &[Option<u64>]) -> Vec<Option<CoreKind>> {
+    let known = match values.iter().copied().collect::<Option<Vec<u64>>>() {
+        Some(known) => known,
+        None => return vec![None; values.len()],
+    };
+
+    let mut distinct = known.clone();
+    distinct.sort_unstable();
+    distinct.dedup();
+    let max = distinct.last().copied().unwrap_or(0);
+    let threshold = distinct
+        .windows(2)
+        .max_by_key(|pair| pair[1] - pair[0])
+        .filter(|pair| (pair[1] - pair[0]) * 100 >= max * HYBRID_GAP_PERCENT)
+        .map(|pair| pair[1]);
+
+    match threshold {
+        Some(threshold) => known
+            .iter()
+            .map(|value| {
+                if *value >= threshold {
+                    Some(CoreKind::Performance)
+                } else {
+                    Some(CoreKind::Efficiency)
+                }
+            })
+            .collect(),
+        None => vec![None; values.len()],
+    }
+}
+
+/// Parses the CPU list format, ex. `0-7,16-23`.
+fn parse_cpu_list(value: &str) -> Result<Vec<u32>> {
+    let mut ids = Vec::new();
+    for range in value.trim().split(',').filter(|range| !range.is_empty()) {
+        let mut parts = range.splitn(2, '-');
+        let start = parts.next().unwrap_or_default().parse::<u32>()?;
+        let end = match parts.next() {
+            Some(end) => end.parse::<u32>()?,
+            None => start,
+        };
+        ids.extend(start..=end);
+    }
+
+    Ok(ids)
+}
+
+fn read_cpu_list(path: &Path) -> Result<Option<Vec<u32>>> {
+    match read_value(path)? {
+        Some(value) => parse_cpu_list(&value).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Intel hybrid CPUs are exposing separate PMU devices for the P-cores and E-cores.
+fn classify_by_pmu(ids: &[u32]) -> Result<Option<Vec<Option<CoreKind>>>> {
+    let root = rt::linux::sysfs_root().join("devices");
+    let performance = read_cpu_list(&root.join("cpu_core/cpus"))?;
+    let efficiency = read_cpu_list(&root.join("cpu_atom/cpus"))?;
+
+    let kinds = match (performance, efficiency) {
+        (Some(performance), Some(efficiency)) => ids
+            .iter()
+            .map(|id| {
+                if performance.contains(id) {
+                    Some(CoreKind::Performance)
+                } else if efficiency.contains(id) {
+                    Some(CoreKind::Efficiency)
+                } else {
+                    None
+                }
+            })
+            .collect(),
+        _ => return Ok(None),
+    };
+
+    Ok(Some(kinds))
+}
+
+fn read_cores() -> Result<Vec<Core>> {
+    let root = rt::linux::sysfs_root().join("devices/system/cpu");
+    let mut ids = Vec::new();
+    for entry in fs::read_dir(&root).map_err(|e| Error::from(e).with_file(&root))? {
+        let name = entry?.file_name();
+        // Skipping `cpufreq`, `cpuidle` and other non-CPU directories
+        if let Some(id) = name
+            .to_str()
+            .and_then(|name| name.strip_prefix("cpu"))
+            .and_then(|id| id.parse::<u32>().ok())
+        {
+            ids.push(id);
+        }
+    }
+    ids.sort_unstable();
+
+    let mut max_frequencies = Vec::with_capacity(ids.len());
+    let mut capacities = Vec::with_capacity(ids.len());
+    for id in ids.iter() {
+        let path = root.join(format!("cpu{}", id));
+        max_frequencies.push(read_number(&path.join("cpufreq/cpuinfo_max_freq"))?);
+        capacities.push(read_number(&path.join("cpu_capacity"))?);
+    }
+
+    let kinds = match classify_by_pmu(&ids)? {
+        Some(kinds) => kinds,
+        None if capacities.iter().all(Option::is_some) => classify(&capacities),
+        None => classify(&max_frequencies),
+    };
+
+    let cores = ids
+        .into_iter()
+        .zip(max_frequencies)
+        .zip(kinds)
+        .map(|((id, max_frequency), kind)| Core {
+            id,
+            max_frequency: max_frequency.map(Frequency::new::<frequency::kilohertz>),
+            kind,
+        })
+        .collect();
+
+    Ok(cores)
+}
+
+pub async fn cores() -> Result<Vec<Core>> {
+    rt::spawn_blocking(read_cores).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{classify, parse_cpu_list};
+    use crate::os::linux::CoreKind;
+
+    #[test]
+    fn test_classify_hybrid() {
+        let kinds = classify(&[Some(4_700_000), Some(4_700_000), Some(3_500_000)]);
+
+        assert_eq!(
+            kinds,
+            vec![
+                Some(CoreKind::Performance),
+                Some(CoreKind::Performance),
+                Some(CoreKind::Efficiency)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_classify_homogeneous() {
+        assert_eq!(classify(&[Some(1024), Some(1024)]), vec![None, None]);
+        assert_eq!(classify(&[Some(1024), None]), vec![None, None]);
+        assert!(classify(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_classify_preferred_cores() {
+        // Turbo Boost Max 3.0 favored cores are a few percents faster than the rest
+        let kinds = classify(&[
+            Some(5_300_000),
+            Some(5_200_000),
+            Some(5_100_000),
+            Some(5_100_000),
+        ]);
+
+        assert_eq!(kinds, vec![None; 4]);
+    }
+
+    #[test]
+    fn test_classify_arm_capacity() {
+        let kinds = classify(&[Some(446), Some(446), Some(871), Some(1024)]);
+
+        assert_eq!(
+            kinds,
+            vec![
+                Some(CoreKind::Efficiency),
+                Some(CoreKind::Efficiency),
+                Some(CoreKind::Performance),
+                Some(CoreKind::Performance)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(
+            parse_cpu_list("0-3,8,10-11\n").unwrap(),
+            vec![0, 1, 2, 3, 8, 10, 11]
+        );
+        assert_eq!(parse_cpu_list("5").unwrap(), vec![5]);
+        assert!(parse_cpu_list("\n").unwrap().is_empty());
+        assert!(parse_cpu_list("0-a").is_err());
+    }
+}
@@ -2,18 +2,9 @@ use std::fs;
 use std::io::{self, BufRead};
 
 use heim_common::prelude::*;
+use heim_common::sys::unix;
 use heim_runtime as rt;
 
-fn sysconf() -> Result<u64> {
-    let result = unsafe { libc::sysconf(libc::_SC_NPROCESSORS_ONLN) };
-
-    if result < 0 {
-        Err(Error::last_os_error().with_sysconf(libc::_SC_NPROCESSORS_ONLN))
-    } else {
-        Ok(result as u64)
-    }
-}
-
 async fn cpuinfo() -> Result<u64> {
     rt::spawn_blocking(|| {
         let f = fs::File::open(rt::linux::procfs_root().join("cpuinfo"))?;
@@ -50,7 +41,7 @@ async fn stat() -> Result<u64> {
 }
 
 pub async fn logical_count() -> Result<u64> {
-    match sysconf() {
+    match unix::sysconf(libc::_SC_NPROCESSORS_ONLN) {
         Ok(value) => Ok(value),
         Err(..) => match cpuinfo().await {
             Ok(value) => Ok(value),
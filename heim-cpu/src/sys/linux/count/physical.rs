@@ -1,24 +1,31 @@
 use std::collections::HashSet;
 use std::fs;
-use std::io::{self, BufRead};
+use std::io;
+use std::path::Path;
 use std::str;
 
 use heim_common::prelude::*;
 use heim_runtime as rt;
 
+fn read_id(path: &Path) -> Result<u64> {
+    let contents = fs::read_to_string(path).map_err(|e| Error::from(e).with_file(path))?;
+
+    Ok(contents.trim().parse()?)
+}
+
 async fn topology() -> Result<u64> {
     rt::spawn_blocking(|| {
-        let path = rt::linux::sysfs_root().join("devices/system/cpu/cpu[0-9]/topology/core_id");
+        let path = rt::linux::sysfs_root().join("devices/system/cpu/cpu[0-9]*/topology");
         let entries =
             glob::glob(path.display().to_string().as_str()).expect("Invalid glob pattern");
-        let mut acc = HashSet::<u64>::new();
+        let mut acc = HashSet::<(u64, u64)>::new();
 
         for entry in entries {
             let entry = entry.map_err(|e| e.into_error())?;
-            let contents = fs::read_to_string(entry)?;
-
-            let core_id = contents.trim().parse()?;
-            let _ = acc.insert(core_id);
+            // Core ids are unique only in the scope of one physical package
+            let package_id = read_id(&entry.join("physical_package_id"))?;
+            let core_id = read_id(&entry.join("core_id"))?;
+            let _ = acc.insert((package_id, core_id));
         }
 
         if !acc.is_empty() {
@@ -32,66 +39,41 @@ async fn topology() -> Result<u64> {
     .await
 }
 
-#[derive(Default)]
-struct Collector {
-    physical_id: Option<u64>,
-    group: HashSet<(u64, u64)>,
+fn parse_value(line: &str) -> Option<u64> {
+    line.split(':').nth(1)?.trim().parse::<u64>().ok()
 }
 
-fn parse_line(line: &str) -> Result<u64> {
-    line.split(':')
-        .nth(2)
-        .map(|value| value.trim())
-        .ok_or_else(|| Error::from(io::Error::from(io::ErrorKind::InvalidData)))
-        .and_then(|value| value.parse::<u64>().map_err(Error::from))
-}
+// Counts unique `(physical id, core id)` pairs in the `/proc/cpuinfo` contents,
+// `None` is returned if there are no such fields at all (ex. for some ARM kernels)
+fn parse_cpu_info(contents: &str) -> Option<u64> {
+    let mut physical_id = None;
+    let mut acc = HashSet::<(u64, u64)>::new();
 
-async fn cpu_info() -> Result<Option<u64>> {
-    rt::spawn_blocking(|| {
-        let mut acc = Collector::default();
-        let f = fs::File::open(rt::linux::procfs_root().join("cpuinfo"))?;
-        let reader = io::BufReader::new(f);
-
-        let lines = reader.lines();
-        for line in lines {
-            match &line? {
-                l if l.starts_with("physical id") => {
-                    let core_id = parse_line(l.as_str())?;
-                    if acc.physical_id.is_none() {
-                        acc.physical_id = Some(core_id)
-                    } else {
-                        // TODO: In general it seems better to return an error
-                        panic!(
-                            "Missed the core id value in the {:?}/cpuinfo, implementation bug",
-                            rt::linux::procfs_root()
-                        );
-                    }
-                }
-                l if l.starts_with("core id") => {
-                    let core_id = parse_line(l.as_str())?;
-                    if acc.physical_id.is_some() {
-                        let physical_id =
-                            acc.physical_id.take().expect("Not expected to be happen");
-                        let _ = acc.group.insert((physical_id, core_id));
-                    } else {
-                        // TODO: In general it seems better to return an error
-                        panic!(
-                            "Missed the physical id value in the {:?}/cpuinfo!",
-                            rt::linux::procfs_root()
-                        );
-                    }
-                }
-                _ => continue,
+    for line in contents.lines() {
+        if line.starts_with("physical id") {
+            physical_id = parse_value(line);
+        } else if line.starts_with("core id") {
+            // `core id` is always following the `physical id` for the same processor
+            if let (Some(physical_id), Some(core_id)) = (physical_id.take(), parse_value(line)) {
+                let _ = acc.insert((physical_id, core_id));
             }
         }
+    }
 
-        if !acc.group.is_empty() {
-            Ok(Some(acc.group.len() as u64))
-        } else {
-            Ok(None)
-        }
-    })
-    .await
+    if !acc.is_empty() {
+        Some(acc.len() as u64)
+    } else {
+        None
+    }
+}
+
+async fn cpu_info() -> Result<Option<u64>> {
+    let path = rt::linux::procfs_root().join("cpuinfo");
+    let contents = rt::fs::read_to_string(&path)
+        .await
+        .map_err(|e| Error::from(e).with_file(&path))?;
+
+    Ok(parse_cpu_info(&contents))
 }
 
 pub async fn physical_count() -> Result<Option<u64>> {
@@ -100,3 +82,40 @@ pub async fn physical_count() -> Result<Option<u64>> {
         Err(..) => cpu_info().await,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::parse_cpu_info;
+
+    #[test]
+    fn test_parse_cpu_info() {
+        const CPU_INFO: &str = "processor\t: 0
+physical id\t: 0
+core id\t\t: 0
+
+processor\t: 1
+physical id\t: 0
+core id\t\t: 1
+
+processor\t: 2
+physical id\t: 0
+core id\t\t: 0
+
+processor\t: 3
+physical id\t: 1
+core id\t\t: 0
+";
+
+        assert_eq!(parse_cpu_info(CPU_INFO), Some(3));
+    }
+
+    #[test]
+    fn test_parse_cpu_info_missing_ids() {
+        const CPU_INFO: &str = "processor\t: 0
+BogoMIPS\t: 48.00
+Features\t: fp asimd evtstrm crc32 cpuid
+";
+
+        assert_eq!(parse_cpu_info(CPU_INFO), None);
+    }
+}
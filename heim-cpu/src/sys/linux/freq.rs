@@ -12,11 +12,12 @@ pub struct CpuFrequency {
     current: Frequency,
     min: Option<Frequency>,
     max: Option<Frequency>,
+    base: Option<Frequency>,
 }
 
 impl CpuFrequency {
-    pub fn current(&self) -> Frequency {
-        self.current
+    pub fn current(&self) -> Option<Frequency> {
+        Some(self.current)
     }
 
     pub fn min(&self) -> Option<Frequency> {
@@ -26,6 +27,10 @@ impl CpuFrequency {
     pub fn max(&self) -> Option<Frequency> {
         self.max
     }
+
+    pub fn base(&self) -> Option<Frequency> {
+        self.base
+    }
 }
 
 impl ops::Add<CpuFrequency> for CpuFrequency {
@@ -45,11 +50,32 @@ impl ops::Add<CpuFrequency> for CpuFrequency {
             (None, Some(right)) => Some(right),
             (None, None) => None,
         };
+        let base = match (self.base, rhs.base) {
+            (Some(left), Some(right)) => Some(left + right),
+            (Some(left), None) => Some(left),
+            (None, Some(right)) => Some(right),
+            (None, None) => None,
+        };
 
-        CpuFrequency { current, max, min }
+        CpuFrequency {
+            current,
+            max,
+            min,
+            base,
+        }
     }
 }
 
+/// Returns logical CPU index for the `/sys/devices/system/cpu/cpuN/cpufreq` path.
+fn cpu_index(path: &Path) -> Option<usize> {
+    path.parent()?
+        .file_name()?
+        .to_str()?
+        .strip_prefix("cpu")?
+        .parse()
+        .ok()
+}
+
 /// Internal blocking iterator over CPU frequencies.
 fn _frequencies() -> impl Iterator<Item = Result<CpuFrequency>> {
     // TODO: psutil looks into `/sys/devices/system/cpu/cpufreq/policy*` at first
@@ -58,19 +84,96 @@ fn _frequencies() -> impl Iterator<Item = Result<CpuFrequency>> {
     // later with the thoughts and patches
 
     // TODO: https://github.com/giampaolo/psutil/issues/1269
-    let path = rt::linux::sysfs_root().join("devices/system/cpu/cpu[0-9]/cpufreq/");
+    let path = rt::linux::sysfs_root().join("devices/system/cpu/cpu[0-9]*/cpufreq/");
 
-    let entries = glob::glob(path.display().to_string().as_str()).expect("Incorrect glob pattern");
+    let mut entries = glob::glob(path.display().to_string().as_str())
+        .expect("Incorrect glob pattern")
+        .collect::<Vec<_>>();
+    // Glob results are sorted lexicographically, so `cpu10` goes before `cpu2`
+    entries.sort_by_key(|entry| entry.as_ref().ok().and_then(|path| cpu_index(path)));
 
-    entries.map(|try_path| {
-        let path = try_path.map_err(|e| e.into_error())?;
+    if entries.is_empty() {
+        // `cpufreq` sysfs tree is missing for some VMs and for kernels without
+        // the CPU frequency scaling support, `/proc/cpuinfo` is the last resort
+        return cpuinfo_frequencies().into_iter();
+    }
 
-        let current = current_freq(&path)?;
-        let max = max_freq(&path);
-        let min = min_freq(&path);
+    let brand = brand_freq();
 
-        Ok(CpuFrequency { current, max, min })
-    })
+    entries
+        .into_iter()
+        .filter_map(move |try_path| {
+            let path = match try_path {
+                Ok(path) => path,
+                Err(e) => return Some(Err(e.into_error().into())),
+            };
+
+            let current = match current_freq(&path) {
+                Ok(current) => current,
+                // Locked down kernels might restrict access to the `cpufreq` files,
+                // these CPUs are skipped instead of failing the whole stream
+                Err(e) if e.is_permission_denied() => return None,
+                Err(e) => return Some(Err(e)),
+            };
+            let max = max_freq(&path);
+            let min = min_freq(&path);
+            let base = base_freq(&path, brand);
+
+            Some(Ok(CpuFrequency {
+                current,
+                max,
+                min,
+                base,
+            }))
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+fn cpuinfo_frequencies() -> Vec<Result<CpuFrequency>> {
+    let path = rt::linux::procfs_root().join("cpuinfo");
+    match fs::read_to_string(&path) {
+        Ok(contents) => parse_cpuinfo_freqs(&contents).into_iter().map(Ok).collect(),
+        Err(e) => vec![Err(Error::from(e).with_file(path))],
+    }
+}
+
+/// Parses the per-CPU `cpu MHz` values from the `/proc/cpuinfo` contents,
+/// ordered by the `processor` index.
+///
+/// Only the current frequency is known in this case;
+/// CPUs without the `cpu MHz` key (ex. for ARM) are skipped.
+fn parse_cpuinfo_freqs(contents: &str) -> Vec<CpuFrequency> {
+    let mut acc = Vec::new();
+    for block in contents.split("\n\n") {
+        let mut index = None;
+        let mut current = None;
+        let mut base = None;
+        for line in block.lines() {
+            let mut parts = line.splitn(2, ':');
+            let key = parts.next().unwrap_or_default().trim();
+            let value = parts.next().unwrap_or_default().trim();
+            match key {
+                "processor" => index = value.parse::<usize>().ok(),
+                "cpu MHz" => current = value.parse::<f64>().ok(),
+                "model name" => base = parse_brand_freq(value),
+                _ => {}
+            }
+        }
+
+        if let (Some(index), Some(current)) = (index, current) {
+            let freq = CpuFrequency {
+                current: Frequency::new::<frequency::kilohertz>((current * 1_000.0).round() as u64),
+                min: None,
+                max: None,
+                base,
+            };
+            acc.push((index, freq));
+        }
+    }
+    acc.sort_by_key(|(index, _)| *index);
+
+    acc.into_iter().map(|(_, freq)| freq).collect()
 }
 
 pub fn frequencies() -> impl Stream<Item = Result<CpuFrequency>> {
@@ -93,6 +196,7 @@ pub async fn frequency() -> Result<CpuFrequency> {
                 current: acc.current / amount,
                 min: acc.min.map(|value| value / amount),
                 max: acc.max.map(|value| value / amount),
+                base: acc.base.map(|value| value / amount),
             })
         } else {
             let inner = io::Error::from(io::ErrorKind::InvalidData);
@@ -127,16 +231,113 @@ fn current_freq(path: &Path) -> Result<Frequency> {
     //    future::ready(result)
 }
 
+// Scaling limits are falling back to the hardware ones,
+// as the `scaling_*` files are missing for some `cpufreq` drivers
+
 fn max_freq(path: &Path) -> Option<Frequency> {
-    let value = read_freq(path.join("scaling_max_freq"));
+    let value = read_freq(path.join("scaling_max_freq"))
+        .or_else(|_| read_freq(path.join("cpuinfo_max_freq")));
 
     // Don't care about errors propagation at this point
     value.ok()
 }
 
 fn min_freq(path: &Path) -> Option<Frequency> {
-    let value = read_freq(path.join("scaling_min_freq"));
+    let value = read_freq(path.join("scaling_min_freq"))
+        .or_else(|_| read_freq(path.join("cpuinfo_min_freq")));
 
     // Don't care about errors propagation at this point
     value.ok()
 }
+
+fn base_freq(path: &Path, brand: Option<Frequency>) -> Option<Frequency> {
+    // `base_frequency` is provided by the `intel_pstate` driver only
+    read_freq(path.join("base_frequency"))
+        .ok()
+        .or(brand)
+        .or_else(|| read_freq(path.join("cpuinfo_max_freq")).ok())
+}
+
+/// Fetches the nominal frequency from the CPU brand string,
+/// which is the same for all cores.
+fn brand_freq() -> Option<Frequency> {
+    let contents = fs::read_to_string(rt::linux::procfs_root().join("cpuinfo")).ok()?;
+
+    contents
+        .lines()
+        .find(|line| line.starts_with("model name"))
+        .and_then(|line| line.split(':').nth(1))
+        .and_then(parse_brand_freq)
+}
+
+// Intel CPUs brand strings are ending with the frequency, ex. `Intel(R) Core(TM) i7-8550U CPU @ 1.80GHz`
+fn parse_brand_freq(brand: &str) -> Option<Frequency> {
+    let value = brand.rsplit('@').next()?.trim();
+    let (value, multiplier) = if let Some(value) = value.strip_suffix("GHz") {
+        (value, 1_000_000.0)
+    } else if let Some(value) = value.strip_suffix("MHz") {
+        (value, 1_000.0)
+    } else {
+        return None;
+    };
+    let value = value.trim_end().parse::<f64>().ok()?;
+
+    Some(Frequency::new::<frequency::kilohertz>(
+        (value * multiplier).round() as u64,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use heim_common::units::frequency;
+
+    use std::path::Path;
+
+    use super::{cpu_index, parse_brand_freq, parse_cpuinfo_freqs};
+
+    #[test]
+    fn test_parse_brand_freq() {
+        let freq = parse_brand_freq(" Intel(R) Core(TM) i7-8550U CPU @ 1.80GHz").unwrap();
+        assert_eq!(freq.get::<frequency::megahertz>(), 1_800);
+
+        let freq = parse_brand_freq("Intel(R) Pentium(R) III CPU @ 800MHz").unwrap();
+        assert_eq!(freq.get::<frequency::megahertz>(), 800);
+
+        assert!(parse_brand_freq("AMD Ryzen 7 3700X 8-Core Processor").is_none());
+    }
+
+    #[test]
+    fn test_cpu_index() {
+        let path = Path::new("/sys/devices/system/cpu/cpu12/cpufreq");
+        assert_eq!(cpu_index(path), Some(12));
+
+        let path = Path::new("/sys/devices/system/cpu/cpufreq");
+        assert_eq!(cpu_index(path), None);
+    }
+
+    #[test]
+    fn test_parse_cpuinfo_freqs() {
+        let contents = "processor\t: 1
+model name\t: Intel(R) Xeon(R) CPU E5-2680 v4 @ 2.40GHz
+cpu MHz\t\t: 2399.998
+
+processor\t: 0
+model name\t: Intel(R) Xeon(R) CPU E5-2680 v4 @ 2.40GHz
+cpu MHz\t\t: 2400.000
+
+processor\t: 2
+model name\t: ARMv7 Processor rev 4 (v7l)
+";
+        let freqs = parse_cpuinfo_freqs(contents);
+        assert_eq!(freqs.len(), 2);
+
+        assert_eq!(freqs[0].current.get::<frequency::kilohertz>(), 2_400_000);
+        assert_eq!(freqs[1].current.get::<frequency::kilohertz>(), 2_399_998);
+        assert!(freqs[0].min.is_none());
+        assert!(freqs[0].max.is_none());
+        assert_eq!(
+            freqs[0].base.map(|base| base.get::<frequency::megahertz>()),
+            Some(2_400)
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use heim_common::prelude::*;
+use heim_common::units::{ratio, Ratio};
+use heim_runtime as rt;
+
+// `/proc/loadavg` contents are looking like `0.20 0.18 0.12 1/80 11206`,
+// where only the first three fields are load averages; runnable/total processes
+// and the last created PID columns are ignored.
+fn parse_loadavg(line: &str) -> Result<(Ratio, Ratio, Ratio)> {
+    let mut parts = line.split_whitespace();
+    let mut next = |name: &'static str| -> Result<Ratio> {
+        let value = parts
+            .next()
+            .ok_or_else(|| Error::missing_key(name, "/proc/loadavg"))?
+            .parse::<f32>()?;
+
+        Ok(Ratio::new::<ratio::ratio>(value))
+    };
+
+    Ok((next("1 minute")?, next("5 minutes")?, next("15 minutes")?))
+}
+
+pub async fn loadavg() -> Result<(Ratio, Ratio, Ratio)> {
+    let path = rt::linux::procfs_root().join("loadavg");
+    let line = rt::fs::read_first_line(path.clone())
+        .await
+        .map_err(|e| Error::from(e).with_file(&path))?;
+
+    parse_loadavg(&line)
+}
+
+#[cfg(test)]
+mod tests {
+    use heim_common::units::ratio;
+
+    use super::parse_loadavg;
+
+    #[test]
+    fn test_parse_loadavg() {
+        let (one, five, fifteen) = parse_loadavg("0.20 0.18 0.12 1/80 11206\n").unwrap();
+        assert!((one.get::<ratio::ratio>() - 0.20).abs() < f32::EPSILON);
+        assert!((five.get::<ratio::ratio>() - 0.18).abs() < f32::EPSILON);
+        assert!((fifteen.get::<ratio::ratio>() - 0.12).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_parse_loadavg_malformed() {
+        assert!(parse_loadavg("").is_err());
+        assert!(parse_loadavg("0.20 0.18").is_err());
+        assert!(parse_loadavg("0.20 0.18 abc 1/80 11206").is_err());
+    }
+}
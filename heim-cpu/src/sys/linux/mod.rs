@@ -1,9 +1,17 @@
+mod caches;
+mod cores;
 mod count;
 mod freq;
+mod loadavg;
+mod quota;
 mod stats;
 mod times;
 
+pub use self::caches::*;
+pub use self::cores::*;
 pub use self::count::*;
 pub use self::freq::*;
+pub use self::loadavg::*;
+pub use self::quota::*;
 pub use self::stats::*;
 pub use self::times::*;
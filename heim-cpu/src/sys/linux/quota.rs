@@ -0,0 +1,260 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use heim_common::prelude::*;
+use heim_common::sys::linux::read_value;
+use heim_runtime as rt;
+
+#[derive(Debug, PartialEq)]
+enum Version {
+    V1,
+    V2,
+}
+
+#[derive(Debug, PartialEq)]
+struct Mount {
+    version: Version,
+    root: String,
+    mount_point: PathBuf,
+}
+
+// `/proc/self/mountinfo` line example:
+// `33 32 0:29 / /sys/fs/cgroup/cpu rw,relatime - cgroup cgroup rw,cpu`
+//
+// cgroup v1 hierarchy with the `cpu` controller is preferred over the v2 one,
+// since in the hybrid setups v2 hierarchy has no controllers attached.
+fn parse_mount_info(contents: &str) -> Option<Mount> {
+    let mut v2 = None;
+    for line in contents.lines() {
+        let mut parts = line.splitn(2, " - ");
+        let mut fields = parts.next()?.split_whitespace().skip(3);
+        let mut super_fields = parts.next()?.split_whitespace();
+        let (root, mount_point) = match (fields.next(), fields.next()) {
+            (Some(root), Some(mount_point)) => (root.to_string(), PathBuf::from(mount_point)),
+            _ => continue,
+        };
+
+        match super_fields.next() {
+            Some("cgroup") => {
+                let has_cpu = super_fields
+                    .nth(1)
+                    .map(|options| options.split(',').any(|option| option == "cpu"))
+                    .unwrap_or(false);
+                if has_cpu {
+                    return Some(Mount {
+                        version: Version::V1,
+                        root,
+                        mount_point,
+                    });
+                }
+            }
+            Some("cgroup2") if v2.is_none() => {
+                v2 = Some(Mount {
+                    version: Version::V2,
+                    root,
+                    mount_point,
+                });
+            }
+            _ => continue,
+        }
+    }
+
+    v2
+}
+
+// `/proc/self/cgroup` lines are `hierarchy-ID:controllers:path`,
+// ex. `4:cpu,cpuacct:/kubepods/pod1` for v1 or `0::/kubepods/pod1` for v2
+fn parse_cgroup_path(contents: &str, version: &Version) -> Option<String> {
+    contents.lines().find_map(|line| {
+        let mut parts = line.splitn(3, ':');
+        let _id = parts.next()?;
+        let controllers = parts.next()?;
+        let path = parts.next()?;
+        let matches = match version {
+            Version::V1 => controllers.split(',').any(|controller| controller == "cpu"),
+            Version::V2 => controllers.is_empty(),
+        };
+
+        if matches {
+            Some(path.to_string())
+        } else {
+            None
+        }
+    })
+}
+
+fn ratio(quota: &str, period: &str) -> Result<f64> {
+    Ok(quota.parse::<f64>()? / period.parse::<f64>()?)
+}
+
+// cgroup v2 `cpu.max` file contents are `$MAX $PERIOD`, where `$MAX` might be `max`
+fn parse_cpu_max(contents: &str) -> Result<Option<f64>> {
+    let mut parts = contents.split_whitespace();
+    match (parts.next(), parts.next()) {
+        (Some("max"), _) => Ok(None),
+        (Some(quota), Some(period)) => ratio(quota, period).map(Some),
+        _ => Err(Error::missing_key("period", "cpu.max")),
+    }
+}
+
+// cgroup v1 `cpu.cfs_quota_us` is `-1` if there is no limit
+fn parse_cfs(quota: &str, period: &str) -> Result<Option<f64>> {
+    match quota.trim() {
+        "-1" => Ok(None),
+        quota => ratio(quota, period.trim()).map(Some),
+    }
+}
+
+fn read_limit(dir: &Path, version: &Version) -> Result<Option<f64>> {
+    match version {
+        Version::V2 => match read_value(dir.join("cpu.max"))? {
+            Some(contents) => parse_cpu_max(&contents),
+            None => Ok(None),
+        },
+        Version::V1 => {
+            let quota = read_value(dir.join("cpu.cfs_quota_us"))?;
+            let period = read_value(dir.join("cpu.cfs_period_us"))?;
+            match (quota, period) {
+                (Some(quota), Some(period)) => parse_cfs(&quota, &period),
+                _ => Ok(None),
+            }
+        }
+    }
+}
+
+fn read_quota() -> Result<Option<f64>> {
+    let path = rt::linux::procfs_root().join("self/mountinfo");
+    let contents = fs::read_to_string(&path).map_err(|e| Error::from(e).with_file(&path))?;
+    let mount = match parse_mount_info(&contents) {
+        Some(mount) => mount,
+        None => return Ok(None),
+    };
+
+    let path = rt::linux::procfs_root().join("self/cgroup");
+    let contents = fs::read_to_string(&path).map_err(|e| Error::from(e).with_file(&path))?;
+    let cgroup = match parse_cgroup_path(&contents, &mount.version) {
+        Some(cgroup) => cgroup,
+        None => return Ok(None),
+    };
+
+    // Inside of the container mount root is usually the container cgroup itself
+    let relative = cgroup
+        .strip_prefix(mount.root.as_str())
+        .unwrap_or(&cgroup)
+        .trim_start_matches('/');
+    let mut dir = mount.mount_point.join(relative);
+    if !dir.exists() {
+        dir = mount.mount_point.clone();
+    }
+
+    effective_limit(&dir, &mount.mount_point, &mount.version)
+}
+
+// Limits are hierarchical, so the effective one is the smallest
+// among this cgroup and all its ancestors up to the hierarchy mount point
+fn effective_limit(dir: &Path, mount_point: &Path, version: &Version) -> Result<Option<f64>> {
+    let mut acc: Option<f64> = None;
+    for dir in dir.ancestors() {
+        if let Some(limit) = read_limit(dir, version)? {
+            acc = Some(acc.map_or(limit, |acc| acc.min(limit)));
+        }
+        if dir == mount_point {
+            break;
+        }
+    }
+
+    Ok(acc)
+}
+
+pub async fn quota() -> Result<Option<f64>> {
+    rt::spawn_blocking(read_quota).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    const MOUNT_INFO_HYBRID: &str = "\
+32 24 0:28 / /sys/fs/cgroup rw,relatime - tmpfs tmpfs rw,mode=755
+33 32 0:29 / /sys/fs/cgroup/cpu,cpuacct rw,relatime - cgroup cgroup rw,cpu,cpuacct
+36 32 0:32 / /sys/fs/cgroup/memory rw,relatime - cgroup cgroup rw,memory
+42 32 0:38 / /sys/fs/cgroup/unified rw,relatime - cgroup2 cgroup2 rw
+";
+
+    const MOUNT_INFO_V2: &str = "\
+22 27 0:21 / /proc rw,nosuid,nodev,noexec,relatime shared:12 - proc proc rw
+30 23 0:26 / /sys/fs/cgroup rw,nosuid,nodev,noexec,relatime shared:4 - cgroup2 cgroup2 rw
+";
+
+    #[test]
+    fn test_parse_mount_info() {
+        assert_eq!(
+            parse_mount_info(MOUNT_INFO_HYBRID),
+            Some(Mount {
+                version: Version::V1,
+                root: "/".to_string(),
+                mount_point: PathBuf::from("/sys/fs/cgroup/cpu,cpuacct"),
+            })
+        );
+        assert_eq!(
+            parse_mount_info(MOUNT_INFO_V2),
+            Some(Mount {
+                version: Version::V2,
+                root: "/".to_string(),
+                mount_point: PathBuf::from("/sys/fs/cgroup"),
+            })
+        );
+        assert_eq!(
+            parse_mount_info("22 27 0:21 / /proc rw - proc proc rw\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_cgroup_path() {
+        const CGROUP: &str = "\
+12:memory:/kubepods/pod1
+4:cpu,cpuacct:/kubepods/pod1/container
+0::/user.slice
+";
+
+        assert_eq!(
+            parse_cgroup_path(CGROUP, &Version::V1).as_deref(),
+            Some("/kubepods/pod1/container")
+        );
+        assert_eq!(
+            parse_cgroup_path(CGROUP, &Version::V2).as_deref(),
+            Some("/user.slice")
+        );
+    }
+
+    #[test]
+    fn test_parse_limits() {
+        assert_eq!(parse_cpu_max("150000 100000\n").unwrap(), Some(1.5));
+        assert_eq!(parse_cpu_max("max 100000\n").unwrap(), None);
+        assert!(parse_cpu_max("150000\n").is_err());
+
+        assert_eq!(parse_cfs("50000\n", "100000\n").unwrap(), Some(0.5));
+        assert_eq!(parse_cfs("-1\n", "100000\n").unwrap(), None);
+    }
+
+    #[test]
+    fn test_effective_limit() {
+        let root = tempfile::tempdir().unwrap();
+        let pod = root.path().join("kubepods/pod1");
+        let container = pod.join("container");
+        fs::create_dir_all(&container).unwrap();
+        fs::write(root.path().join("cpu.max"), "100000 100000\n").unwrap();
+        fs::write(root.path().join("kubepods/cpu.max"), "400000 100000\n").unwrap();
+        fs::write(pod.join("cpu.max"), "150000 100000\n").unwrap();
+        fs::write(container.join("cpu.max"), "max 100000\n").unwrap();
+
+        let limit = effective_limit(&container, &root.path().join("kubepods"), &Version::V2);
+        assert_eq!(limit.unwrap(), Some(1.5));
+
+        let limit = effective_limit(&container, &container, &Version::V2);
+        assert_eq!(limit.unwrap(), None);
+    }
+}
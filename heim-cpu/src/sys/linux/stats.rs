@@ -62,5 +62,35 @@ impl FromStr for CpuStats {
 }
 
 pub async fn stats() -> Result<CpuStats> {
+    rt::linux::ensure_procfs().await?;
+
     rt::fs::read_into(rt::linux::procfs_root().join("stat")).await
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::CpuStats;
+
+    #[test]
+    fn test_total_interrupts() {
+        // `intr` and `softirq` lines are containing the total value
+        // followed by the per-IRQ breakdown
+        let irqs = vec!["7"; 4096].join(" ");
+        let contents = format!(
+            "cpu  465552918 3813058 111153634 9065060137 5821166 0 4346876 0 0 0\n\
+             intr 8589934592 {}\n\
+             ctxt 17179869184\n\
+             btime 1602496672\n\
+             softirq 12345 1 2 3 4 5 6 7 8 9 10\n",
+            irqs
+        );
+
+        let stats = CpuStats::from_str(&contents).unwrap();
+
+        assert_eq!(stats.interrupts(), 8_589_934_592);
+        assert_eq!(stats.ctx_switches(), 17_179_869_184);
+        assert_eq!(stats.soft_interrupts(), 12345);
+    }
+}
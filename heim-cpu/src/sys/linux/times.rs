@@ -1,53 +1,96 @@
 use std::str::{self, FromStr};
 
 use heim_common::prelude::*;
-use heim_common::sys::unix::CLOCK_TICKS;
+use heim_common::sys::unix::clock_ticks_cached;
 use heim_common::units::{time, Time};
 use heim_runtime as rt;
 
+// Raw values are kept in the `USER_HZ` units (clock ticks),
+// so they can be used as is without the precision loss.
 #[derive(Debug, Default, Clone)]
 pub struct CpuTime {
-    user: Time,
-    nice: Time,
-    system: Time,
-    idle: Time,
-    io_wait: Time,
-    irq: Time,
-    soft_irq: Time,
-    steal: Time,
-    guest: Option<Time>,
-    guest_nice: Option<Time>,
+    cpu: Option<usize>,
+    user: u64,
+    nice: u64,
+    system: u64,
+    idle: u64,
+    io_wait: u64,
+    irq: u64,
+    soft_irq: u64,
+    steal: u64,
+    guest: Option<u64>,
+    guest_nice: Option<u64>,
+    // `USER_HZ` value, fetched when the line is parsed
+    clock_ticks: f64,
 }
 
 impl CpuTime {
+    fn ticks_to_time(&self, ticks: u64) -> Time {
+        Time::new::<time::second>(ticks as f64 / self.clock_ticks)
+    }
+
+    pub fn cpu(&self) -> Option<usize> {
+        self.cpu
+    }
     pub fn user(&self) -> Time {
-        self.user
+        self.ticks_to_time(self.user)
     }
     pub fn nice(&self) -> Time {
-        self.nice
+        self.ticks_to_time(self.nice)
     }
     pub fn system(&self) -> Time {
-        self.system
+        self.ticks_to_time(self.system)
     }
     pub fn idle(&self) -> Time {
-        self.idle
+        self.ticks_to_time(self.idle)
     }
     pub fn io_wait(&self) -> Time {
-        self.io_wait
+        self.ticks_to_time(self.io_wait)
     }
     pub fn irq(&self) -> Time {
-        self.irq
+        self.ticks_to_time(self.irq)
     }
     pub fn soft_irq(&self) -> Time {
-        self.soft_irq
+        self.ticks_to_time(self.soft_irq)
     }
     pub fn steal(&self) -> Time {
-        self.steal
+        self.ticks_to_time(self.steal)
     }
     pub fn guest(&self) -> Option<Time> {
-        self.guest
+        self.guest.map(|ticks| self.ticks_to_time(ticks))
     }
     pub fn guest_nice(&self) -> Option<Time> {
+        self.guest_nice.map(|ticks| self.ticks_to_time(ticks))
+    }
+
+    pub fn user_ticks(&self) -> u64 {
+        self.user
+    }
+    pub fn nice_ticks(&self) -> u64 {
+        self.nice
+    }
+    pub fn system_ticks(&self) -> u64 {
+        self.system
+    }
+    pub fn idle_ticks(&self) -> u64 {
+        self.idle
+    }
+    pub fn io_wait_ticks(&self) -> u64 {
+        self.io_wait
+    }
+    pub fn irq_ticks(&self) -> u64 {
+        self.irq
+    }
+    pub fn soft_irq_ticks(&self) -> u64 {
+        self.soft_irq
+    }
+    pub fn steal_ticks(&self) -> u64 {
+        self.steal
+    }
+    pub fn guest_ticks(&self) -> Option<u64> {
+        self.guest
+    }
+    pub fn guest_nice_ticks(&self) -> Option<u64> {
         self.guest_nice
     }
 }
@@ -58,16 +101,21 @@ impl FromStr for CpuTime {
     // Parse one line from the /proc/stat, ex.
     // "cpu1 317865 456 71065 3101075 8645 14938 10567 0 0 0"
     fn from_str(value: &str) -> Result<CpuTime> {
-        let mut times = CpuTime::default();
-        let ticks = *CLOCK_TICKS as f64;
+        let mut times = CpuTime {
+            clock_ticks: clock_ticks_cached()?,
+            ..CpuTime::default()
+        };
+
+        let mut parts = value.split_whitespace();
+        // Summary line is labeled as `cpu`, per-CPU lines are labeled as `cpuN`
+        times.cpu = match parts.next().and_then(|label| label.strip_prefix("cpu")) {
+            Some("") => None,
+            Some(index) => Some(index.parse::<usize>()?),
+            None => return Err(Error::missing_key("cpu", "/proc/stat line")),
+        };
 
-        let parts = value.split_whitespace().skip(1);
         for (idx, part) in parts.enumerate() {
-            let value = part.parse::<f64>().map(|value| {
-                // TODO: Potential precision loss.
-                // Do we care about it at all?
-                Time::new::<time::second>(value / ticks)
-            })?;
+            let value = part.parse::<u64>()?;
 
             match idx {
                 0 => times.user = value,
@@ -89,20 +137,28 @@ impl FromStr for CpuTime {
 }
 
 pub async fn time() -> Result<CpuTime> {
-    // cumulative time is always the first line
-    let lines =
-        rt::fs::read_lines_into::<_, CpuTime, _>(rt::linux::procfs_root().join("stat")).await?;
-    rt::pin!(lines);
-    match lines.next().await {
-        Some(line) => line,
-        None => Err(Error::missing_key(
+    rt::linux::ensure_procfs().await?;
+
+    // Cumulative time is always the first line,
+    // so there is no need to read the per-CPU lines at all
+    let path = rt::linux::procfs_root().join("stat");
+    let line = rt::fs::read_first_line(path.clone())
+        .await
+        .map_err(|e| Error::from(e).with_file(&path))?;
+
+    if line.starts_with("cpu ") {
+        CpuTime::from_str(&line)
+    } else {
+        Err(Error::missing_key(
             "cumulative time line",
-            format!("{:?}/stat", rt::linux::procfs_root()),
-        )),
+            format!("{:?}", path),
+        ))
     }
 }
 
 pub async fn times() -> Result<impl Stream<Item = Result<CpuTime>>> {
+    rt::linux::ensure_procfs().await?;
+
     let lines = rt::fs::read_lines(rt::linux::procfs_root().join("stat")).await?;
 
     let stream = lines.skip(1).filter_map(|try_line| async move {
@@ -120,6 +176,9 @@ pub async fn times() -> Result<impl Stream<Item = Result<CpuTime>>> {
 mod tests {
     use std::str::FromStr;
 
+    use heim_common::sys::unix::clock_ticks_cached;
+    use heim_common::units::time;
+
     use super::CpuTime;
 
     #[test]
@@ -128,4 +187,45 @@ mod tests {
 
         let _ = CpuTime::from_str(LINE).unwrap();
     }
+
+    #[test]
+    fn test_cpu_index() {
+        let summary = CpuTime::from_str("cpu  4705 356 584 3699 23 23 0 0 0 0").unwrap();
+        assert_eq!(summary.cpu(), None);
+
+        let cpu =
+            CpuTime::from_str("cpu12 1393280 32966 572056 13343292 6130 0 17875 0 0 0").unwrap();
+        assert_eq!(cpu.cpu(), Some(12));
+        assert_eq!(
+            cpu.user().get::<time::second>(),
+            1393280.0 / clock_ticks_cached().unwrap()
+        );
+
+        assert!(CpuTime::from_str("intr 114930548 113199788 3 0 5").is_err());
+    }
+
+    #[test]
+    fn test_ticks() {
+        let times =
+            CpuTime::from_str("cpu  465552918 3813058 111153634 9065060137 5821166 0 4346876 0 0")
+                .unwrap();
+        let ticks = clock_ticks_cached().unwrap();
+
+        assert_eq!(times.user_ticks(), 465552918);
+        assert_eq!(times.idle_ticks(), 9065060137);
+        assert_eq!(times.guest_ticks(), Some(0));
+        assert_eq!(times.guest_nice_ticks(), None);
+        assert_eq!(
+            times.user_ticks() as f64 / ticks,
+            times.user().get::<time::second>()
+        );
+        assert_eq!(
+            times.system_ticks() as f64 / ticks,
+            times.system().get::<time::second>()
+        );
+        assert_eq!(
+            times.idle_ticks() as f64 / ticks,
+            times.idle().get::<time::second>()
+        );
+    }
 }
@@ -0,0 +1,39 @@
+use heim_common::prelude::*;
+use heim_common::sys::macos::sysctl;
+use heim_common::units::{information, Information};
+
+use crate::{Cache, CacheKind};
+
+// Some sysctls might be missing (ex. `hw.l3cachesize` for Apple Silicon),
+// or might have zero values; these caches are skipped.
+fn cache_size(key: &[u8]) -> Option<Information> {
+    match unsafe { sysctl::sysctlbyname::<i64>(key) } {
+        Ok(size) if size > 0 => Some(Information::new::<information::byte>(size as u64)),
+        _ => None,
+    }
+}
+
+pub async fn caches() -> Result<Vec<Cache>> {
+    let line_size = cache_size(b"hw.cachelinesize\0");
+
+    let keys: [(u8, CacheKind, &[u8]); 4] = [
+        (1, CacheKind::Data, b"hw.l1dcachesize\0"),
+        (1, CacheKind::Instruction, b"hw.l1icachesize\0"),
+        (2, CacheKind::Unified, b"hw.l2cachesize\0"),
+        (3, CacheKind::Unified, b"hw.l3cachesize\0"),
+    ];
+
+    let caches = keys
+        .iter()
+        .filter_map(|(level, kind, key)| {
+            cache_size(key).map(|size| Cache {
+                level: *level,
+                kind: *kind,
+                size,
+                line_size,
+            })
+        })
+        .collect();
+
+    Ok(caches)
+}
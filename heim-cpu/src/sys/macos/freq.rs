@@ -5,33 +5,46 @@ use super::bindings;
 
 #[derive(Debug)]
 pub struct CpuFrequency {
-    current: Frequency,
-    min: Frequency,
-    max: Frequency,
+    current: Option<Frequency>,
+    min: Option<Frequency>,
+    max: Option<Frequency>,
 }
 
 impl CpuFrequency {
-    pub fn current(&self) -> Frequency {
+    pub fn current(&self) -> Option<Frequency> {
         self.current
     }
 
     pub fn min(&self) -> Option<Frequency> {
-        Some(self.min)
+        self.min
     }
 
     pub fn max(&self) -> Option<Frequency> {
-        Some(self.max)
+        self.max
+    }
+
+    // `hw.cpufrequency` is the nominal frequency already
+    pub fn base(&self) -> Option<Frequency> {
+        self.current
     }
 }
 
-pub async fn frequency() -> Result<CpuFrequency> {
-    let current = bindings::cpu_frequency()?;
-    let min = bindings::cpu_frequency_min()?;
-    let max = bindings::cpu_frequency_max()?;
+// Apple Silicon CPUs are not providing `hw.cpufrequency*` sysctls at all,
+// and zero values are returned for them when running under Rosetta 2,
+// so both cases are considered as an unknown frequency.
+fn known(value: Result<u64>) -> Result<Option<Frequency>> {
+    match value {
+        Ok(0) => Ok(None),
+        Ok(hz) => Ok(Some(Frequency::new::<frequency::hertz>(hz))),
+        Err(e) if e.raw_os_error() == Some(libc::ENOENT) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
 
+pub async fn frequency() -> Result<CpuFrequency> {
     Ok(CpuFrequency {
-        current: Frequency::new::<frequency::hertz>(current),
-        min: Frequency::new::<frequency::hertz>(min),
-        max: Frequency::new::<frequency::hertz>(max),
+        current: known(bindings::cpu_frequency())?,
+        min: known(bindings::cpu_frequency_min())?,
+        max: known(bindings::cpu_frequency_max())?,
     })
 }
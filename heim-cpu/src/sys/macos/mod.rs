@@ -1,3 +1,4 @@
+mod caches;
 mod count;
 mod freq;
 mod stats;
@@ -5,6 +6,7 @@ mod times;
 
 mod bindings;
 
+pub use self::caches::*;
 pub use self::count::*;
 pub use self::freq::*;
 pub use self::stats::*;
@@ -1,5 +1,5 @@
 use heim_common::prelude::*;
-use heim_common::sys::unix::CLOCK_TICKS;
+use heim_common::sys::unix::clock_ticks_cached;
 use heim_common::units::{time, Time};
 
 use super::bindings;
@@ -30,40 +30,30 @@ impl CpuTime {
     }
 }
 
-impl From<bindings::host_cpu_load_info> for CpuTime {
-    fn from(info: bindings::host_cpu_load_info) -> CpuTime {
-        let ticks = *CLOCK_TICKS as f64;
-
-        CpuTime {
-            user: Time::new::<time::second>(f64::from(info.user) / ticks),
-            nice: Time::new::<time::second>(f64::from(info.nice) / ticks),
-            system: Time::new::<time::second>(f64::from(info.system) / ticks),
-            idle: Time::new::<time::second>(f64::from(info.idle) / ticks),
-        }
-    }
-}
-
-impl From<bindings::processor_cpu_load_info> for CpuTime {
-    fn from(info: bindings::processor_cpu_load_info) -> CpuTime {
-        let ticks = *CLOCK_TICKS as f64;
-
-        CpuTime {
-            user: Time::new::<time::second>(f64::from(info.user) / ticks),
-            nice: Time::new::<time::second>(f64::from(info.nice) / ticks),
-            system: Time::new::<time::second>(f64::from(info.system) / ticks),
-            idle: Time::new::<time::second>(f64::from(info.idle) / ticks),
-        }
+impl CpuTime {
+    fn from_ticks(user: u32, nice: u32, system: u32, idle: u32) -> Result<CpuTime> {
+        let ticks = clock_ticks_cached()?;
+
+        Ok(CpuTime {
+            user: Time::new::<time::second>(f64::from(user) / ticks),
+            nice: Time::new::<time::second>(f64::from(nice) / ticks),
+            system: Time::new::<time::second>(f64::from(system) / ticks),
+            idle: Time::new::<time::second>(f64::from(idle) / ticks),
+        })
     }
 }
 
 pub async fn time() -> Result<CpuTime> {
-    bindings::cpu_load_info().map(Into::into)
+    let info = bindings::cpu_load_info()?;
+
+    CpuTime::from_ticks(info.user, info.nice, info.system, info.idle)
 }
 
 pub async fn times() -> Result<impl Stream<Item = Result<CpuTime>>> {
     let processors = bindings::processor_load_info()?;
 
-    let stream = stream::iter(processors).map(|proc_info| Ok(CpuTime::from(proc_info)));
+    let stream = stream::iter(processors)
+        .map(|info| CpuTime::from_ticks(info.user, info.nice, info.system, info.idle));
 
     Ok(stream)
 }
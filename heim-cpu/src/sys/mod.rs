@@ -1,6 +1,7 @@
-#[cfg(unix)]
+// Linux version reads the `/proc/loadavg` file directly instead
+#[cfg(all(unix, not(target_os = "linux")))]
 mod unix;
-#[cfg(unix)]
+#[cfg(all(unix, not(target_os = "linux")))]
 pub use self::unix::*;
 
 cfg_if::cfg_if! {
@@ -0,0 +1,42 @@
+use winapi::um::winnt;
+
+use heim_common::prelude::*;
+use heim_common::units::{information, Information};
+
+use super::wrappers::count::LogicalProcessors;
+use crate::{Cache, CacheKind};
+
+pub async fn caches() -> Result<Vec<Cache>> {
+    let processors = LogicalProcessors::get()?;
+
+    let caches = processors
+        .iter()
+        .filter(|p| p.Relationship == winnt::RelationCache)
+        .filter_map(|p| {
+            let cache = unsafe { p.u.Cache() };
+
+            // Only caches available to the first logical CPU are returned
+            if cache.GroupMask.Group != 0 || cache.GroupMask.Mask & 1 == 0 {
+                return None;
+            }
+
+            let kind = match cache.Type {
+                winnt::CacheData => CacheKind::Data,
+                winnt::CacheInstruction => CacheKind::Instruction,
+                winnt::CacheUnified => CacheKind::Unified,
+                _ => return None,
+            };
+
+            Some(Cache {
+                level: cache.Level,
+                kind,
+                size: Information::new::<information::byte>(u64::from(cache.CacheSize)),
+                line_size: Some(Information::new::<information::byte>(u64::from(
+                    cache.LineSize,
+                ))),
+            })
+        })
+        .collect();
+
+    Ok(caches)
+}
@@ -15,8 +15,10 @@ use super::bindings::power::PROCESSOR_POWER_INFORMATION;
 pub struct CpuFrequency(PROCESSOR_POWER_INFORMATION);
 
 impl CpuFrequency {
-    pub fn current(&self) -> Frequency {
-        Frequency::new::<frequency::megahertz>(self.0.CurrentMhz.into())
+    pub fn current(&self) -> Option<Frequency> {
+        Some(Frequency::new::<frequency::megahertz>(
+            self.0.CurrentMhz.into(),
+        ))
     }
 
     pub fn max(&self) -> Option<Frequency> {
@@ -26,6 +28,11 @@ impl CpuFrequency {
     pub fn min(&self) -> Option<Frequency> {
         None
     }
+
+    // `MaxMhz` is the rated processor frequency, boost frequencies are not reported there
+    pub fn base(&self) -> Option<Frequency> {
+        self.max()
+    }
 }
 
 fn get_processors() -> Result<Vec<PROCESSOR_POWER_INFORMATION>> {
@@ -1,3 +1,4 @@
+mod caches;
 mod count;
 mod freq;
 mod stats;
@@ -6,6 +7,7 @@ mod times;
 mod bindings;
 mod wrappers;
 
+pub use self::caches::*;
 pub use self::count::*;
 pub use self::freq::*;
 pub use self::stats::*;
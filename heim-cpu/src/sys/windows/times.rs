@@ -4,13 +4,15 @@ use winapi::um::processthreadsapi;
 use super::bindings::winternl;
 use heim_common::prelude::*;
 use heim_common::sys::IntoTime as _;
-use heim_common::units::Time;
+use heim_common::units::{time, Time};
 
 #[derive(Debug, Clone)]
 pub struct CpuTime {
     user: Time,
     system: Time,
     idle: Time,
+    interrupt: Time,
+    dpc: Time,
 }
 
 impl CpuTime {
@@ -25,6 +27,32 @@ impl CpuTime {
     pub fn idle(&self) -> Time {
         self.idle
     }
+
+    pub fn interrupt(&self) -> Time {
+        self.interrupt
+    }
+
+    pub fn dpc(&self) -> Time {
+        self.dpc
+    }
+}
+
+impl CpuTime {
+    fn from_proc_info(proc_info: &winternl::SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION) -> CpuTime {
+        let user = proc_info.UserTime.into_time();
+        let idle = proc_info.IdleTime.into_time();
+        let system = proc_info.KernelTime.into_time() - idle;
+        let interrupt = proc_info.InterruptTime.into_time();
+        let dpc = proc_info.DpcTime.into_time();
+
+        CpuTime {
+            user,
+            system,
+            idle,
+            interrupt,
+            dpc,
+        }
+    }
 }
 
 // https://docs.microsoft.com/en-us/windows/desktop/api/processthreadsapi/nf-processthreadsapi-getsystemtimes
@@ -36,29 +64,41 @@ pub async fn time() -> Result<CpuTime> {
     let result = unsafe { processthreadsapi::GetSystemTimes(&mut idle, &mut kernel, &mut user) };
 
     if result == 0 {
-        Err(Error::last_os_error().with_ffi("GetSystemTimes"))
-    } else {
-        let user = user.into_time();
-        let idle = idle.into_time();
-        // Same as `psutil` subtracting idle time
-        // and leaving only busy kernel time
-        let system = kernel.into_time() - idle;
-
-        Ok(CpuTime { user, system, idle })
+        return Err(Error::last_os_error().with_ffi("GetSystemTimes"));
     }
+
+    let user = user.into_time();
+    let idle = idle.into_time();
+    // Same as `psutil` subtracting idle time
+    // and leaving only busy kernel time
+    let system = kernel.into_time() - idle;
+
+    // `GetSystemTimes` does not provide interrupt and DPC times,
+    // so, same as `psutil`, they are summed from all the processors
+    let processors: Vec<winternl::SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION> =
+        winternl::query_system_information()?;
+    let (interrupt, dpc) = processors.iter().map(CpuTime::from_proc_info).fold(
+        (
+            Time::new::<time::second>(0.0),
+            Time::new::<time::second>(0.0),
+        ),
+        |(interrupt, dpc), cpu| (interrupt + cpu.interrupt, dpc + cpu.dpc),
+    );
+
+    Ok(CpuTime {
+        user,
+        system,
+        idle,
+        interrupt,
+        dpc,
+    })
 }
 
 pub async fn times() -> Result<impl Stream<Item = Result<CpuTime>>> {
     let processors: Vec<winternl::SYSTEM_PROCESSOR_PERFORMANCE_INFORMATION> =
         winternl::query_system_information()?;
 
-    let stream = stream::iter(processors).map(|proc_info| {
-        let user = proc_info.UserTime.into_time();
-        let idle = proc_info.IdleTime.into_time();
-        let system = proc_info.KernelTime.into_time() - idle;
-
-        Ok(CpuTime { user, system, idle })
-    });
+    let stream = stream::iter(processors).map(|proc_info| Ok(CpuTime::from_proc_info(&proc_info)));
 
     Ok(stream)
 }
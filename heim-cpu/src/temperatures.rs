@@ -0,0 +1,23 @@
+use heim_common::prelude::*;
+
+/// CPU package or core temperature sensor.
+///
+/// This is the same type as the [`heim_sensors::TemperatureSensor`],
+/// which [source] is always a [`TemperatureSource::Cpu`].
+///
+/// [`heim_sensors::TemperatureSensor`]: ../heim_sensors/struct.TemperatureSensor.html
+/// [source]: ../heim_sensors/struct.TemperatureSensor.html#method.source
+/// [`TemperatureSource::Cpu`]: ../heim_sensors/enum.TemperatureSource.html#variant.Cpu
+#[cfg_attr(docsrs, doc(cfg(feature = "sensors")))]
+pub type CpuTemperature = heim_sensors::TemperatureSensor;
+
+/// Returns a stream over the CPU package and cores temperature sensors.
+///
+/// This is a shortcut for the [`heim_sensors::cpu_temperatures`] function,
+/// available with the `sensors` feature enabled.
+///
+/// [`heim_sensors::cpu_temperatures`]: ../heim_sensors/fn.cpu_temperatures.html
+#[cfg_attr(docsrs, doc(cfg(feature = "sensors")))]
+pub fn temperatures() -> impl Stream<Item = Result<CpuTemperature>> {
+    heim_sensors::cpu_temperatures()
+}
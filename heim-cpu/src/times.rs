@@ -48,16 +48,41 @@ impl fmt::Debug for CpuTime {
     }
 }
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde_crate::Serialize for CpuTime {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde_crate::Serializer,
+    {
+        use heim_common::serialize::Quantity;
+        use serde_crate::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("CpuTime", 3)?;
+        state.serialize_field("user", &Quantity(&self.user()))?;
+        state.serialize_field("system", &Quantity(&self.system()))?;
+        state.serialize_field("idle", &Quantity(&self.idle()))?;
+        state.end()
+    }
+}
+
 /// Returns cumulative value of all [CPU times].
 ///
+/// This is a cheaper call than [times] if only the total CPU time is needed,
+/// as for Linux only the first (summary) line of the `/proc/stat` is read.
+///
 /// [CPU times]: struct.CpuTime.html
+/// [times]: ./fn.times.html
 pub async fn time() -> Result<CpuTime> {
     sys::time().await.map(Into::into)
 }
 
 /// Returns a stream over the [CPU time] for each CPU core.
 ///
+/// This is a per-CPU breakdown of the [time] function value.
+///
 /// [CPU time]: struct.CpuTime.html
+/// [time]: ./fn.time.html
 pub async fn times() -> Result<impl Stream<Item = Result<CpuTime>>> {
     let inner = sys::times().await?;
 
@@ -10,9 +10,14 @@ use heim_cpu as cpu;
 async fn smoke_frequency() {
     let freq = cpu::frequency().await.unwrap();
 
-    assert!(freq.current().get::<frequency::hertz>() > 0);
+    if let Some(current) = freq.current() {
+        assert!(current.get::<frequency::hertz>() > 0);
+    }
     let _ = freq.min();
     let _ = freq.max();
+    if let Some(base) = freq.base() {
+        assert!(base.get::<frequency::hertz>() > 0);
+    }
 }
 
 #[heim_derive::test]
@@ -26,6 +31,55 @@ async fn smoke_frequencies() {
         let _ = f.current();
         let _ = f.min();
         let _ = f.max();
+        let _ = f.base();
+    }
+}
+
+#[heim_derive::test]
+async fn smoke_frequency_watch() {
+    let watch = cpu::frequency_watch(std::time::Duration::from_millis(10));
+    futures::pin_mut!(watch);
+    // Frequencies might be unavailable (ex. in VMs),
+    // but stream should not be terminated by the errors
+    let readings = watch.take(2).collect::<Vec<_>>().await;
+
+    assert_eq!(readings.len(), 2);
+}
+
+#[heim_derive::test]
+#[cfg(unix)]
+async fn smoke_loadavg_watch() {
+    let watch = cpu::os::unix::loadavg_watch(std::time::Duration::from_millis(10));
+    futures::pin_mut!(watch);
+    let readings = watch.take(2).collect::<Vec<_>>().await;
+
+    assert_eq!(readings.len(), 2);
+    for loadavg in readings {
+        let _ = loadavg.unwrap();
+    }
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_cores() {
+    let cores = cpu::os::linux::cores().await.unwrap();
+
+    for core in cores {
+        let _ = core.id();
+        let _ = core.max_frequency();
+        let _ = core.kind();
+    }
+}
+
+#[heim_derive::test]
+async fn smoke_caches() {
+    let caches = cpu::caches().await.unwrap();
+
+    for cache in caches {
+        assert!(cache.level() > 0);
+        let _ = cache.kind();
+        let _ = cache.size();
+        let _ = cache.line_size();
     }
 }
 
@@ -73,6 +127,7 @@ async fn smoke_time() {
     {
         use heim_cpu::os::linux::CpuTimeExt;
 
+        assert!(time.cpu().is_none());
         let _ = time.nice();
         let _ = time.io_wait();
         let _ = time.irq();
@@ -80,6 +135,18 @@ async fn smoke_time() {
         let _ = time.steal();
         let _ = time.guest();
         let _ = time.guest_nice();
+
+        let _ = time.user_ticks();
+        let _ = time.idle_ticks();
+        assert_eq!(time.guest_ticks().is_some(), time.guest().is_some());
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        use heim_cpu::os::windows::CpuTimeExt;
+
+        let _ = time.irq();
+        let _ = time.soft_irq();
     }
 }
 
@@ -98,6 +165,7 @@ async fn smoke_times() {
         {
             use heim_cpu::os::linux::CpuTimeExt;
 
+            assert!(time.cpu().is_some());
             let _ = time.nice();
             let _ = time.io_wait();
             let _ = time.irq();
@@ -106,6 +174,27 @@ async fn smoke_times() {
             let _ = time.guest();
             let _ = time.guest_nice();
         }
+
+        #[cfg(target_os = "windows")]
+        {
+            use heim_cpu::os::windows::CpuTimeExt;
+
+            let _ = time.irq();
+            let _ = time.soft_irq();
+        }
+    }
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "windows")]
+async fn smoke_times_idle() {
+    use heim_common::units::time;
+
+    let times = cpu::times().await.unwrap();
+    futures::pin_mut!(times);
+    while let Some(cpu_time) = times.next().await {
+        let idle = cpu_time.unwrap().idle();
+        assert!(idle.get::<time::second>() > 0.0);
     }
 }
 
@@ -135,3 +224,24 @@ async fn smoke_cpu_physical_count() {
 async fn smoke_cpu_usage() {
     let _measurement = cpu::usage().await.unwrap();
 }
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_quota() {
+    if let Some(quota) = cpu::os::linux::quota().await.unwrap() {
+        assert!(quota > 0.0);
+    }
+}
+
+#[heim_derive::test]
+#[cfg(feature = "sensors")]
+async fn smoke_temperatures() {
+    let mut temperatures = cpu::temperatures().boxed_local();
+    while let Some(sensor) = temperatures.next().await {
+        let sensor = sensor.unwrap();
+
+        let _ = sensor.unit();
+        let _ = sensor.label();
+        let _ = sensor.current();
+    }
+}
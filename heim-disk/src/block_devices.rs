@@ -0,0 +1,91 @@
+use std::fmt;
+
+use heim_common::prelude::*;
+use heim_common::units::Information;
+
+use crate::sys;
+
+/// Block device, such as a disk or a disk partition.
+///
+/// Unlike the [Partition], block devices are enumerated
+/// no matter if they are mounted or not.
+///
+/// [Partition]: ./struct.Partition.html
+pub struct BlockDevice(sys::BlockDevice);
+
+wrap!(BlockDevice, sys::BlockDevice);
+
+impl BlockDevice {
+    /// Returns block device name, ex. `sda` for Linux, `disk0` for macOS
+    /// or `\\.\PhysicalDrive0` for Windows.
+    pub fn name(&self) -> &str {
+        self.as_ref().name()
+    }
+
+    /// Returns block device size.
+    pub fn size(&self) -> Information {
+        self.as_ref().size()
+    }
+
+    /// Returns device model name if available.
+    ///
+    /// Partitions are not having the model name.
+    pub fn model(&self) -> Option<&str> {
+        self.as_ref().model()
+    }
+
+    /// Returns `true` if it is a rotational device (ex. HDD),
+    /// or `false` for non-rotational ones (ex. SSD).
+    ///
+    /// ## Returns
+    ///
+    /// Returns `None` if value can't be determined.
+    pub fn is_rotational(&self) -> Option<bool> {
+        self.as_ref().is_rotational()
+    }
+
+    /// Returns `true` if device has removable media.
+    pub fn is_removable(&self) -> bool {
+        self.as_ref().is_removable()
+    }
+
+    /// Returns parent device name if this block device is a partition.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Windows partitions are not enumerated yet and `None` is always returned.
+    pub fn parent(&self) -> Option<&str> {
+        self.as_ref().parent()
+    }
+}
+
+impl fmt::Debug for BlockDevice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BlockDevice")
+            .field("name", &self.name())
+            .field("size", &self.size())
+            .field("model", &self.model())
+            .field("is_rotational", &self.is_rotational())
+            .field("is_removable", &self.is_removable())
+            .field("parent", &self.parent())
+            .finish()
+    }
+}
+
+/// Returns a stream over all [block devices] available in system,
+/// including the unmounted ones.
+///
+/// Each partition is yielded right after its parent device.
+///
+/// ## Compatibility
+///
+/// * For Linux devices are enumerated from the `/sys/block/` directory
+/// * For macOS `IOMedia` IOKit services are used
+/// * For Windows `\\.\PhysicalDriveN` devices are queried via the `DeviceIoControl` function
+///
+/// [block devices]: ./struct.BlockDevice.html
+pub async fn block_devices() -> Result<impl Stream<Item = Result<BlockDevice>>> {
+    let inner = sys::block_devices().await?;
+
+    Ok(inner.map_ok(Into::into))
+}
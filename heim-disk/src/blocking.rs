@@ -0,0 +1,49 @@
+//! Synchronous versions of the disk information functions.
+//!
+//! Each function drives the corresponding async function to completion
+//! on the current thread, so there is no need to set up any async runtime.
+//!
+//! ```rust
+//! let partitions = heim_disk::blocking::partitions()?;
+//! # Ok::<(), heim_common::Error>(())
+//! ```
+
+use std::path::Path;
+
+use heim_common::prelude::*;
+use heim_runtime as rt;
+
+use crate::{IoCounters, Partition, Usage};
+
+/// Synchronous version of the [io_counters](../fn.io_counters.html) function.
+pub fn io_counters() -> Result<Vec<IoCounters>> {
+    rt::block_on(async { crate::io_counters().await?.try_collect().await })
+}
+
+/// Synchronous version of the [io_counters_for](../fn.io_counters_for.html) function.
+pub fn io_counters_for(name: &str) -> Result<Option<IoCounters>> {
+    rt::block_on(crate::io_counters_for(name))
+}
+
+/// Synchronous version of the [io_counters_physical](../fn.io_counters_physical.html) function.
+pub fn io_counters_physical() -> Result<Vec<IoCounters>> {
+    rt::block_on(async { crate::io_counters_physical().await?.try_collect().await })
+}
+
+/// Synchronous version of the [partitions](../fn.partitions.html) function.
+pub fn partitions() -> Result<Vec<Partition>> {
+    rt::block_on(async { crate::partitions().await?.try_collect().await })
+}
+
+/// Synchronous version of the [partitions_physical](../fn.partitions_physical.html) function.
+pub fn partitions_physical() -> Result<Vec<Partition>> {
+    rt::block_on(async { crate::partitions_physical().await?.try_collect().await })
+}
+
+/// Synchronous version of the [usage](../fn.usage.html) function.
+pub fn usage<T>(path: T) -> Result<Usage>
+where
+    T: AsRef<Path>,
+{
+    rt::block_on(crate::usage(path))
+}
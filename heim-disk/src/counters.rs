@@ -1,8 +1,12 @@
-use std::ffi::OsStr;
+use std::collections::HashMap;
+use std::ffi::{OsStr, OsString};
 use std::fmt;
+use std::time::Duration;
 
 use heim_common::prelude::*;
-use heim_common::units::Information;
+use heim_common::units::{Information, InformationRate, Time};
+use heim_common::utils::delta;
+use heim_runtime as rt;
 
 use crate::sys;
 
@@ -46,6 +50,48 @@ impl IoCounters {
     pub fn write_bytes(&self) -> Information {
         self.as_ref().write_bytes()
     }
+
+    /// Calculates [IO rates] between the `previous` sample of the same disk counters
+    /// and this one, taken `elapsed` time after it.
+    ///
+    /// This is a shortcut for the [`delta`] method followed by the [`IoCountersDelta::rates`] call,
+    /// so if some counter was reset or had wrapped around between the samples,
+    /// its current value is used as a delta too.
+    ///
+    /// [IO rates]: struct.IoRates.html
+    /// [`delta`]: #method.delta
+    /// [`IoCountersDelta::rates`]: struct.IoCountersDelta.html#method.rates
+    pub fn rate_since(&self, previous: &IoCounters, elapsed: Time) -> IoRates {
+        self.delta(previous, elapsed).rates()
+    }
+
+    /// Calculates [IO counters delta] between the `previous` sample of the same disk counters
+    /// and this one, taken `elapsed` time after it.
+    ///
+    /// If some counter was reset or had wrapped around between the samples,
+    /// its current value is used as a delta, as it is assumed that counter started
+    /// from zero again. [`rate_since`] method calculates rates in the same way.
+    ///
+    /// [IO counters delta]: struct.IoCountersDelta.html
+    /// [`rate_since`]: #method.rate_since
+    pub fn delta(&self, previous: &IoCounters, elapsed: Time) -> IoCountersDelta {
+        IoCountersDelta {
+            read_count: delta::counter_delta_since_reset(self.read_count(), previous.read_count()),
+            write_count: delta::counter_delta_since_reset(
+                self.write_count(),
+                previous.write_count(),
+            ),
+            read_bytes: delta::information_delta_since_reset(
+                self.read_bytes(),
+                previous.read_bytes(),
+            ),
+            write_bytes: delta::information_delta_since_reset(
+                self.write_bytes(),
+                previous.write_bytes(),
+            ),
+            elapsed,
+        }
+    }
 }
 
 impl fmt::Debug for IoCounters {
@@ -60,6 +106,137 @@ impl fmt::Debug for IoCounters {
     }
 }
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde_crate::Serialize for IoCounters {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde_crate::Serializer,
+    {
+        use heim_common::serialize::Quantity;
+        use serde_crate::ser::SerializeStruct;
+
+        #[cfg(not(target_os = "linux"))]
+        let fields = 5;
+        #[cfg(target_os = "linux")]
+        let fields = 11;
+
+        let mut state = serializer.serialize_struct("IoCounters", fields)?;
+        state.serialize_field("device_name", &self.device_name().to_string_lossy())?;
+        state.serialize_field("read_count", &self.read_count())?;
+        state.serialize_field("write_count", &self.write_count())?;
+        state.serialize_field("read_bytes", &Quantity(&self.read_bytes()))?;
+        state.serialize_field("write_bytes", &Quantity(&self.write_bytes()))?;
+
+        #[cfg(target_os = "linux")]
+        {
+            use crate::os::linux::IoCountersExt;
+
+            state.serialize_field("busy_time", &Quantity(&self.busy_time()))?;
+            state.serialize_field("read_merged_count", &self.read_merged_count())?;
+            state.serialize_field("write_merged_count", &self.write_merged_count())?;
+            state.serialize_field("discard_count", &self.discard_count())?;
+            state.serialize_field("discard_merged_count", &self.discard_merged_count())?;
+            state.serialize_field("discard_bytes", &Quantity(&self.discard_bytes()))?;
+        }
+
+        state.end()
+    }
+}
+
+/// Disk I/O rates, calculated between two [IO counters] samples.
+///
+/// See [`IoCounters::rate_since`] method.
+///
+/// [IO counters]: struct.IoCounters.html
+/// [`IoCounters::rate_since`]: struct.IoCounters.html#method.rate_since
+#[derive(Debug, Clone, Copy)]
+pub struct IoRates {
+    read_count: f64,
+    write_count: f64,
+    read_bytes: InformationRate,
+    write_bytes: InformationRate,
+}
+
+impl IoRates {
+    /// Returns number of reads per second.
+    pub fn read_count(&self) -> f64 {
+        self.read_count
+    }
+
+    /// Returns number of writes per second.
+    pub fn write_count(&self) -> f64 {
+        self.write_count
+    }
+
+    /// Returns read information rate.
+    pub fn read_bytes(&self) -> InformationRate {
+        self.read_bytes
+    }
+
+    /// Returns written information rate.
+    pub fn write_bytes(&self) -> InformationRate {
+        self.write_bytes
+    }
+}
+
+/// Difference between two [IO counters] samples of the same disk.
+///
+/// See [`IoCounters::delta`] method.
+///
+/// [IO counters]: struct.IoCounters.html
+/// [`IoCounters::delta`]: struct.IoCounters.html#method.delta
+#[derive(Debug, Clone, Copy)]
+pub struct IoCountersDelta {
+    read_count: u64,
+    write_count: u64,
+    read_bytes: Information,
+    write_bytes: Information,
+    elapsed: Time,
+}
+
+impl IoCountersDelta {
+    /// Returns number of reads between the samples.
+    pub fn read_count(&self) -> u64 {
+        self.read_count
+    }
+
+    /// Returns number of writes between the samples.
+    pub fn write_count(&self) -> u64 {
+        self.write_count
+    }
+
+    /// Returns number of bytes read between the samples.
+    pub fn read_bytes(&self) -> Information {
+        self.read_bytes
+    }
+
+    /// Returns number of bytes written between the samples.
+    pub fn write_bytes(&self) -> Information {
+        self.write_bytes
+    }
+
+    /// Returns time elapsed between the samples.
+    pub fn elapsed(&self) -> Time {
+        self.elapsed
+    }
+
+    /// Returns [IO rates] for this delta, dividing it by the [elapsed] time.
+    ///
+    /// All rates are zero if the elapsed time is zero or negative.
+    ///
+    /// [IO rates]: struct.IoRates.html
+    /// [elapsed]: #method.elapsed
+    pub fn rates(&self) -> IoRates {
+        IoRates {
+            read_count: delta::delta_per_second(self.read_count, self.elapsed),
+            write_count: delta::delta_per_second(self.write_count, self.elapsed),
+            read_bytes: delta::information_delta_rate(self.read_bytes, self.elapsed),
+            write_bytes: delta::information_delta_rate(self.write_bytes, self.elapsed),
+        }
+    }
+}
+
 /// Returns a stream over [IO counters] for all disks available in system.
 ///
 /// ## Compatibility
@@ -82,3 +259,149 @@ pub async fn io_counters_physical() -> Result<impl Stream<Item = Result<IoCounte
 
     Ok(inner.map_ok(Into::into))
 }
+
+/// Returns [IO counters] for the disk with the given [device name],
+/// or `None` if there is no such disk.
+///
+/// Same as looking for a disk in the [io_counters] stream, but the lookup stops
+/// as soon as the matching disk is found.
+///
+/// ## Compatibility
+///
+/// * For Linux `name` can be either a kernel device name (ex. `sda` or `dm-0`)
+///   or a device path (ex. `/dev/sda`), including the symlinks to it (ex. `/dev/mapper/root`),
+///   which are resolved to the kernel device name
+/// * For macOS `name` can be either a BSD device name (ex. `disk0`) or a device path (ex. `/dev/disk0`)
+/// * For Windows `name` should be a volume path, same as returned by the [device name] method
+///
+/// [IO counters]: struct.IoCounters.html
+/// [device name]: struct.IoCounters.html#method.device_name
+/// [io_counters]: fn.io_counters.html
+pub async fn io_counters_for(name: &str) -> Result<Option<IoCounters>> {
+    let counters = sys::io_counters_for(name).await?;
+
+    Ok(counters.map(Into::into))
+}
+
+/// Returns [IO counters] for all disks available in system, keyed by the [device name].
+///
+/// Same as the [io_counters] function, but convenient for the lookups by the device name.
+///
+/// [IO counters]: struct.IoCounters.html
+/// [device name]: struct.IoCounters.html#method.device_name
+/// [io_counters]: fn.io_counters.html
+pub async fn io_counters_map() -> Result<HashMap<OsString, IoCounters>> {
+    let counters = io_counters().await?;
+
+    counters
+        .try_fold(HashMap::new(), |mut acc, counter| {
+            let _ = acc.insert(counter.device_name().to_os_string(), counter);
+            future::ok(acc)
+        })
+        .await
+}
+
+async fn io_counters_snapshot(capacity: usize) -> Result<Vec<IoCounters>> {
+    let counters = io_counters().await?;
+
+    counters
+        .try_fold(Vec::with_capacity(capacity), |mut acc, counter| {
+            acc.push(counter);
+            future::ok(acc)
+        })
+        .await
+}
+
+/// Returns a stream which yields [IO counters] snapshot for all disks every `interval`.
+///
+/// First snapshot is yielded immediately, and the stream never ends.
+/// Failed snapshot fetch is yielded as an error without terminating the stream.
+///
+/// Consecutive snapshots can be used with the [`IoCounters::rate_since`]
+/// or [`IoCounters::delta`] methods to calculate I/O rates for each disk.
+///
+/// [IO counters]: struct.IoCounters.html
+/// [`IoCounters::rate_since`]: struct.IoCounters.html#method.rate_since
+/// [`IoCounters::delta`]: struct.IoCounters.html#method.delta
+pub fn io_counters_watch(interval: Duration) -> impl Stream<Item = Result<Vec<IoCounters>>> {
+    let ticks = stream::once(future::ready(())).chain(rt::time::interval(interval));
+
+    // Previous snapshot length is used as a capacity hint for the next one,
+    // so the snapshot vector is not re-allocated while it is filled
+    stream::unfold((ticks, 0), |(mut ticks, capacity)| async move {
+        ticks.next().await?;
+        let snapshot = io_counters_snapshot(capacity).await;
+        let capacity = snapshot.as_ref().map_or(capacity, Vec::len);
+
+        Some((snapshot, (ticks, capacity)))
+    })
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use std::str::FromStr;
+
+    use heim_common::units::{information, information_rate, time, Time};
+
+    use super::IoCounters;
+    use crate::sys;
+
+    fn counters(line: &str) -> IoCounters {
+        sys::IoCounters::from_str(line).unwrap().into()
+    }
+
+    #[test]
+    fn test_delta_since_reset() {
+        let previous = counters("8 0 sda 5000 0 4000 0 300 0 200 0 0 0 0");
+        // Reads were reset (ex. device was re-attached), while writes kept counting
+        let current = counters("8 0 sda 20 0 16 0 400 0 300 0 0 0 0");
+        let delta = current.delta(&previous, Time::new::<time::second>(2.0));
+
+        assert_eq!(delta.read_count(), 20);
+        assert_eq!(delta.read_bytes().get::<information::byte>(), 16 * 512);
+        assert_eq!(delta.write_count(), 100);
+        assert_eq!(delta.write_bytes().get::<information::byte>(), 100 * 512);
+
+        let rates = delta.rates();
+        assert_eq!(rates.read_count(), 10.0);
+        assert_eq!(
+            rates
+                .read_bytes()
+                .get::<information_rate::byte_per_second>(),
+            8.0 * 512.0
+        );
+        assert_eq!(rates.write_count(), 50.0);
+    }
+
+    #[test]
+    fn test_rate_since_reset() {
+        let previous = counters("8 0 sda 5000 0 4000 0 300 0 200 0 0 0 0");
+        let current = counters("8 0 sda 20 0 16 0 400 0 300 0 0 0 0");
+        let elapsed = Time::new::<time::second>(2.0);
+        let rates = current.rate_since(&previous, elapsed);
+        let delta_rates = current.delta(&previous, elapsed).rates();
+
+        assert_eq!(rates.read_count(), 10.0);
+        assert_eq!(rates.read_count(), delta_rates.read_count());
+        assert_eq!(rates.write_count(), delta_rates.write_count());
+        assert_eq!(rates.read_bytes(), delta_rates.read_bytes());
+        assert_eq!(rates.write_bytes(), delta_rates.write_bytes());
+    }
+
+    #[test]
+    fn test_delta_zero_elapsed() {
+        let previous = counters("8 0 sda 10 0 10 0 10 0 10 0 0 0 0");
+        let current = counters("8 0 sda 20 0 20 0 20 0 20 0 0 0 0");
+        let rates = current
+            .delta(&previous, Time::new::<time::second>(0.0))
+            .rates();
+
+        assert_eq!(rates.read_count(), 0.0);
+        assert_eq!(
+            rates
+                .write_bytes()
+                .get::<information_rate::byte_per_second>(),
+            0.0
+        );
+    }
+}
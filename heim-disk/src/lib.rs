@@ -27,12 +27,32 @@
 pub mod os;
 mod sys;
 
+#[cfg(feature = "blocking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub mod blocking;
+
+mod block_devices;
 mod counters;
 mod filesystem;
 mod partitions;
 mod usage;
 
+pub use self::block_devices::*;
 pub use self::counters::*;
 pub use self::filesystem::*;
 pub use self::partitions::*;
 pub use self::usage::*;
+
+/// Re-exports of the commonly used disk types and the measurement units for them.
+///
+/// ```rust
+/// use heim_disk::prelude::*;
+/// ```
+pub mod prelude {
+    pub use crate::{
+        BlockDevice, FileSystem, IoCounters, IoCountersDelta, IoRates, Partition, Usage,
+    };
+    pub use heim_common::units::{
+        information, information_rate, ratio, time, Information, InformationRate, Ratio, Time,
+    };
+}
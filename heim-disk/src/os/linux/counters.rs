@@ -0,0 +1,55 @@
+use heim_common::units::{Information, Time};
+
+/// Linux-specific extension for [IoCounters].
+///
+/// Values are parsed from the `/proc/diskstats` file.
+///
+/// [IoCounters]: ../../struct.IoCounters.html
+pub trait IoCountersExt {
+    /// Returns time spent doing I/Os.
+    fn busy_time(&self) -> Time;
+
+    /// Returns amount of the adjacent reads merged into one.
+    fn read_merged_count(&self) -> u64;
+
+    /// Returns amount of the adjacent writes merged into one.
+    fn write_merged_count(&self) -> u64;
+
+    /// Returns amount of the completed discard (trim) requests.
+    ///
+    /// Discard values are available since Linux 4.18, zero is returned for the older kernels.
+    fn discard_count(&self) -> u64;
+
+    /// Returns amount of the adjacent discard requests merged into one.
+    fn discard_merged_count(&self) -> u64;
+
+    /// Returns information amount discarded.
+    fn discard_bytes(&self) -> Information;
+}
+
+#[cfg(target_os = "linux")]
+impl IoCountersExt for crate::IoCounters {
+    fn busy_time(&self) -> Time {
+        self.as_ref().busy_time()
+    }
+
+    fn read_merged_count(&self) -> u64 {
+        self.as_ref().read_merged_count()
+    }
+
+    fn write_merged_count(&self) -> u64 {
+        self.as_ref().write_merged_count()
+    }
+
+    fn discard_count(&self) -> u64 {
+        self.as_ref().discard_count()
+    }
+
+    fn discard_merged_count(&self) -> u64 {
+        self.as_ref().discard_merged_count()
+    }
+
+    fn discard_bytes(&self) -> Information {
+        self.as_ref().discard_bytes()
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt;
+
+use heim_common::prelude::*;
+use heim_common::units::Information;
+
+use crate::sys;
+
+/// Thin-provisioned block device usage.
+///
+/// Unlike the file system-level [Usage] statistics,
+/// values are representing the block device space itself.
+///
+/// [Usage]: ../../struct.Usage.html
+pub struct DeviceUsage(sys::DeviceUsage);
+
+wrap!(DeviceUsage, sys::DeviceUsage);
+
+impl DeviceUsage {
+    /// Returns information amount provisioned for the device,
+    /// which is the device size visible to its users.
+    pub fn provisioned(&self) -> Information {
+        self.as_ref().provisioned()
+    }
+
+    /// Returns information amount actually allocated for the device in the thin pool.
+    pub fn allocated(&self) -> Information {
+        self.as_ref().allocated()
+    }
+}
+
+impl fmt::Debug for DeviceUsage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DeviceUsage")
+            .field("provisioned", &self.provisioned())
+            .field("allocated", &self.allocated())
+            .finish()
+    }
+}
+
+/// Returns [DeviceUsage] for the thin-provisioned device-mapper device with `name` kernel name
+/// (ex. `dm-3`, as seen in the `/sys/block/` directory).
+///
+/// Returns `Ok(None)` if device is not a thin-provisioned device (`thin` target of the device-mapper).
+///
+/// ## Compatibility
+///
+/// Device-mapper status is fetched via the `/dev/mapper/control` device,
+/// which requires `CAP_SYS_ADMIN` capability; error is returned otherwise.
+///
+/// [DeviceUsage]: ./struct.DeviceUsage.html
+pub async fn device_usage(name: &str) -> Result<Option<DeviceUsage>> {
+    sys::device_usage(name)
+        .await
+        .map(|usage| usage.map(Into::into))
+}
@@ -0,0 +1,9 @@
+//! Linux-specific extensions.
+
+mod counters;
+mod device_usage;
+mod partitions;
+
+pub use self::counters::*;
+pub use self::device_usage::*;
+pub use self::partitions::*;
@@ -0,0 +1,102 @@
+bitflags::bitflags! {
+    // These flags are declared at `include/uapi/linux/mount.h`
+    /// Partition mount flags.
+    ///
+    /// Flags are parsed from the mount options string,
+    /// see [`PartitionExt::options`] for options not represented here.
+    ///
+    /// [`PartitionExt::options`]: ./trait.PartitionExt.html#tymethod.options
+    pub struct Flags: libc::c_ulong {
+        /// Mount read-only (`ro`)
+        const MS_RDONLY = 0x0000_0001;
+        /// Ignore `setuid` and `setgid` bits (`nosuid`)
+        const MS_NOSUID = 0x0000_0002;
+        /// Disallow access to device special files (`nodev`)
+        const MS_NODEV = 0x0000_0004;
+        /// Disallow program execution (`noexec`)
+        const MS_NOEXEC = 0x0000_0008;
+        /// Writes are synced at once (`sync`)
+        const MS_SYNCHRONOUS = 0x0000_0010;
+        /// Allow mandatory locks (`mand`)
+        const MS_MANDLOCK = 0x0000_0040;
+        /// Directory modifications are synchronous (`dirsync`)
+        const MS_DIRSYNC = 0x0000_0080;
+        /// Do not update access times (`noatime`)
+        const MS_NOATIME = 0x0000_0400;
+        /// Do not update directory access times (`nodiratime`)
+        const MS_NODIRATIME = 0x0000_0800;
+        /// Update access times relative to modify or change time (`relatime`)
+        const MS_RELATIME = 0x0020_0000;
+        /// Always perform access times updates (`strictatime`)
+        const MS_STRICTATIME = 0x0100_0000;
+        /// Update times lazily (`lazytime`)
+        const MS_LAZYTIME = 0x0200_0000;
+    }
+}
+
+impl Flags {
+    pub(crate) fn from_options(options: &str) -> Flags {
+        options
+            .split(',')
+            .fold(Flags::empty(), |flags, option| match option {
+                "ro" => flags | Flags::MS_RDONLY,
+                "nosuid" => flags | Flags::MS_NOSUID,
+                "nodev" => flags | Flags::MS_NODEV,
+                "noexec" => flags | Flags::MS_NOEXEC,
+                "sync" => flags | Flags::MS_SYNCHRONOUS,
+                "mand" => flags | Flags::MS_MANDLOCK,
+                "dirsync" => flags | Flags::MS_DIRSYNC,
+                "noatime" => flags | Flags::MS_NOATIME,
+                "nodiratime" => flags | Flags::MS_NODIRATIME,
+                "relatime" => flags | Flags::MS_RELATIME,
+                "strictatime" => flags | Flags::MS_STRICTATIME,
+                "lazytime" => flags | Flags::MS_LAZYTIME,
+                _ => flags,
+            })
+    }
+}
+
+/// Linux-specific extension for [Partition] struct.
+///
+/// [Partition]: ../../struct.Partition.html
+pub trait PartitionExt {
+    /// Returns raw mount options string, ex. `rw,nosuid,relatime,data=ordered`.
+    fn options(&self) -> &str;
+
+    /// Mount flags
+    fn flags(&self) -> Flags;
+}
+
+#[cfg(target_os = "linux")]
+impl PartitionExt for crate::Partition {
+    fn options(&self) -> &str {
+        self.as_ref().options()
+    }
+
+    fn flags(&self) -> Flags {
+        Flags::from_options(self.options())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{Flags, PartitionExt};
+    use crate::{sys, Partition};
+
+    #[test]
+    fn test_flags() {
+        let line = "/dev/sda3 /tmp ext4 rw,nosuid,nodev,noexec,relatime 0 0";
+        let partition = Partition::from(sys::Partition::from_str(line).unwrap());
+        let flags = partition.flags();
+
+        assert_eq!(partition.options(), "rw,nosuid,nodev,noexec,relatime");
+        assert!(!flags.contains(Flags::MS_RDONLY));
+        assert!(flags.contains(Flags::MS_NOSUID));
+        assert!(flags.contains(Flags::MS_NODEV));
+        assert!(flags.contains(Flags::MS_NOEXEC));
+        assert!(flags.contains(Flags::MS_RELATIME));
+        assert!(!flags.contains(Flags::MS_NOATIME));
+    }
+}
@@ -7,6 +7,11 @@
 pub mod unix;
 
 // TODO: These too
+//#[cfg(any(target_os = "linux", doc))]
+//#[cfg_attr(docsrs, doc(cfg(target_os = "linux")))]
+#[cfg(target_os = "linux")]
+pub mod linux;
+
 //#[cfg(any(target_os = "macos", doc))]
 //#[cfg_attr(docsrs, doc(cfg(target_os = "macos")))]
 #[cfg(target_os = "macos")]
@@ -1,3 +1,5 @@
+use heim_common::units::Information;
+
 bitflags::bitflags! {
     /// Various options that were employed when mounting this filesystem (see `statvfs(3)`).
     ///
@@ -75,6 +77,14 @@ bitflags::bitflags! {
 pub trait UsageExt {
     /// Returns [Flags] for current filesystem;
     fn flags(&self) -> Flags;
+
+    /// Returns information amount reserved for the privileged users.
+    ///
+    /// This space is not included into the [free] value,
+    /// which is an amount available for the unprivileged users.
+    ///
+    /// [free]: ../../struct.Usage.html#method.free
+    fn reserved(&self) -> Information;
 }
 
 #[cfg(unix)]
@@ -82,4 +92,8 @@ impl UsageExt for crate::Usage {
     fn flags(&self) -> Flags {
         self.as_ref().flags()
     }
+
+    fn reserved(&self) -> Information {
+        self.as_ref().reserved()
+    }
 }
@@ -3,6 +3,7 @@ use std::fmt;
 use std::path::Path;
 
 use heim_common::prelude::*;
+use heim_common::units::Information;
 
 use crate::{sys, usage, FileSystem, Usage};
 
@@ -13,9 +14,24 @@ use crate::{sys, usage, FileSystem, Usage};
 /// See [os]-specific extension traits also.
 ///
 /// [os]: ./os/index.html
-pub struct Partition(sys::Partition);
+pub struct Partition {
+    inner: sys::Partition,
+    total: Option<Information>,
+}
+
+#[doc(hidden)]
+impl AsRef<sys::Partition> for Partition {
+    fn as_ref(&self) -> &sys::Partition {
+        &self.inner
+    }
+}
 
-wrap!(Partition, sys::Partition);
+#[doc(hidden)]
+impl From<sys::Partition> for Partition {
+    fn from(inner: sys::Partition) -> Partition {
+        Partition { inner, total: None }
+    }
+}
 
 impl Partition {
     /// Returns partition device name if available.
@@ -43,6 +59,41 @@ impl Partition {
     pub async fn usage(&self) -> Result<Usage> {
         usage(self.mount_point()).await
     }
+
+    /// Returns total information amount available in partition.
+    ///
+    /// Value is fetched eagerly during the partitions enumeration
+    /// and it is the same as the [`Usage::total`] value returned by the [`usage`] method.
+    ///
+    /// ## Returns
+    ///
+    /// Returns `None` if partition was not yielded by the [partitions_with_usage] stream.
+    ///
+    /// [`Usage::total`]: ./struct.Usage.html#method.total
+    /// [`usage`]: #method.usage
+    /// [partitions_with_usage]: ./fn.partitions_with_usage.html
+    pub fn total(&self) -> Option<Information> {
+        self.total
+    }
+
+    /// Checks if partition is located on an encrypted volume.
+    ///
+    /// Partitions which are not backed by any block device, such as `tmpfs`,
+    /// are considered as not encrypted.
+    ///
+    /// ## Compatibility
+    ///
+    /// * For Linux it detects dm-crypt (including LUKS) devices,
+    ///   also when they are used under the other device-mapper targets, such as LVM
+    /// * For macOS it detects FileVault, encrypted APFS and CoreStorage volumes
+    /// * For Windows [BitLocker] detection is not implemented yet and error is always returned,
+    ///   [`Error::is_not_supported`] returns `true` for it
+    ///
+    /// [BitLocker]: https://docs.microsoft.com/en-us/windows/security/information-protection/bitlocker/bitlocker-overview
+    /// [`Error::is_not_supported`]: ../heim_common/struct.Error.html#method.is_not_supported
+    pub async fn is_encrypted(&self) -> Result<bool> {
+        self.as_ref().is_encrypted().await
+    }
 }
 
 impl fmt::Debug for Partition {
@@ -51,10 +102,33 @@ impl fmt::Debug for Partition {
             .field("device", &self.device())
             .field("mount_point", &self.mount_point())
             .field("file_system", &self.file_system())
+            .field("total", &self.total())
             .finish()
     }
 }
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde_crate::Serialize for Partition {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde_crate::Serializer,
+    {
+        use heim_common::serialize::Quantity;
+        use serde_crate::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Partition", 4)?;
+        state.serialize_field(
+            "device",
+            &self.device().map(|device| device.to_string_lossy()),
+        )?;
+        state.serialize_field("mount_point", &self.mount_point().to_string_lossy())?;
+        state.serialize_field("file_system", self.file_system().as_str())?;
+        state.serialize_field("total", &Quantity(&self.total()))?;
+        state.end()
+    }
+}
+
 /// Returns a stream over mounted disk [Partitions].
 ///
 /// This includes all virtual partitions, such as `tmpfs`.
@@ -75,3 +149,23 @@ pub async fn partitions_physical() -> Result<impl Stream<Item = Result<Partition
 
     Ok(inner.map_ok(Into::into))
 }
+
+/// Returns a stream over mounted disk [Partitions] with the [`total`] value populated.
+///
+/// Same as [partitions], but additionally fetches disk usage for each partition
+/// during the enumeration, so there is no need to call [`Partition::usage`] separately
+/// just to get the partition size.
+///
+/// [Partitions]: struct.Partition.html
+/// [`total`]: struct.Partition.html#method.total
+/// [`Partition::usage`]: struct.Partition.html#method.usage
+pub async fn partitions_with_usage() -> Result<impl Stream<Item = Result<Partition>>> {
+    let inner = partitions().await?;
+
+    Ok(inner.and_then(|mut partition| async move {
+        let usage = partition.usage().await?;
+        partition.total = Some(usage.total());
+
+        Ok(partition)
+    }))
+}
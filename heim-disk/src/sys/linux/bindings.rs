@@ -0,0 +1,139 @@
+//! Minimal subset of the device-mapper ioctl interface.
+//!
+//! See `include/uapi/linux/dm-ioctl.h` in the Linux sources.
+
+use std::ffi::CStr;
+use std::fs;
+use std::io;
+use std::mem;
+use std::os::unix::io::AsRawFd;
+
+use heim_common::prelude::*;
+
+const DM_CONTROL_PATH: &str = "/dev/mapper/control";
+
+const DM_VERSION_MAJOR: u32 = 4;
+const DM_NAME_LEN: usize = 128;
+const DM_UUID_LEN: usize = 129;
+
+const DM_TABLE_STATUS_CMD: libc::c_ulong = 12;
+// `_IOWR(DM_IOCTL, DM_TABLE_STATUS_CMD, struct dm_ioctl)`
+const DM_TABLE_STATUS: libc::c_ulong = (3 << 30)
+    | ((mem::size_of::<dm_ioctl>() as libc::c_ulong) << 16)
+    | (0xfd << 8)
+    | DM_TABLE_STATUS_CMD;
+
+const DM_BUFFER_FULL_FLAG: u32 = 1 << 8;
+
+const INITIAL_BUFFER_SIZE: usize = 16 * 1024;
+
+#[allow(non_camel_case_types)]
+#[repr(C)]
+struct dm_ioctl {
+    version: [u32; 3],
+    data_size: u32,
+    data_start: u32,
+    target_count: u32,
+    open_count: i32,
+    flags: u32,
+    event_nr: u32,
+    padding: u32,
+    dev: u64,
+    name: [libc::c_char; DM_NAME_LEN],
+    uuid: [libc::c_char; DM_UUID_LEN],
+    data: [libc::c_char; 7],
+}
+
+#[allow(non_camel_case_types)]
+#[repr(C)]
+struct dm_target_spec {
+    sector_start: u64,
+    length: u64,
+    status: i32,
+    next: u32,
+    target_type: [libc::c_char; 16],
+}
+
+/// Single target of the device-mapper table.
+#[derive(Debug)]
+pub struct Target {
+    /// Target length in the 512 bytes sectors.
+    pub length: u64,
+    /// Target type, ex. `linear`, `thin` or `crypt`.
+    pub target_type: String,
+    /// Target-specific status line.
+    pub status: String,
+}
+
+/// Fetches status lines for all targets of the device-mapper device with the `name` name.
+///
+/// Same as `dmsetup status {name}` call; requires `CAP_SYS_ADMIN` capability.
+pub fn table_status(name: &str) -> Result<Vec<Target>> {
+    if name.len() >= DM_NAME_LEN {
+        let inner = io::Error::from(io::ErrorKind::InvalidInput);
+        return Err(Error::from(inner).with_message(format!("Invalid dm device name {}", name)));
+    }
+
+    let control =
+        fs::File::open(DM_CONTROL_PATH).map_err(|e| Error::from(e).with_file(DM_CONTROL_PATH))?;
+
+    let mut size = INITIAL_BUFFER_SIZE;
+    loop {
+        // `u64` items are used to keep buffer properly aligned for the `dm_ioctl` struct
+        let mut buffer = vec![0u64; size / mem::size_of::<u64>()];
+        let header = buffer.as_mut_ptr() as *mut dm_ioctl;
+
+        unsafe {
+            (*header).version = [DM_VERSION_MAJOR, 0, 0];
+            (*header).data_size = size as u32;
+            (*header).data_start = mem::size_of::<dm_ioctl>() as u32;
+            for (dst, src) in (*header).name.iter_mut().zip(name.bytes()) {
+                *dst = src as libc::c_char;
+            }
+
+            // Request argument type differs between glibc and musl
+            #[allow(trivial_numeric_casts)]
+            let result = libc::ioctl(control.as_raw_fd(), DM_TABLE_STATUS as _, header);
+            if result == -1 {
+                return Err(Error::last_os_error().with_ffi("ioctl"));
+            }
+
+            if (*header).flags & DM_BUFFER_FULL_FLAG != 0 {
+                size *= 2;
+                continue;
+            }
+
+            return Ok(parse_targets(header, size));
+        }
+    }
+}
+
+unsafe fn parse_targets(header: *const dm_ioctl, size: usize) -> Vec<Target> {
+    let base = header as *const u8;
+    let header = &*header;
+    let data_start = header.data_start as usize;
+    let mut offset = data_start;
+    let mut targets = Vec::with_capacity(header.target_count as usize);
+
+    for _ in 0..header.target_count {
+        if offset + mem::size_of::<dm_target_spec>() > size {
+            break;
+        }
+        let spec = &*(base.add(offset) as *const dm_target_spec);
+        let target_type = CStr::from_ptr(spec.target_type.as_ptr());
+        let status = CStr::from_ptr(
+            base.add(offset + mem::size_of::<dm_target_spec>()) as *const libc::c_char
+        );
+
+        targets.push(Target {
+            length: spec.length,
+            target_type: target_type.to_string_lossy().into_owned(),
+            status: status.to_string_lossy().into_owned(),
+        });
+
+        // `next` is an offset from the data start
+        offset = data_start + spec.next as usize;
+    }
+
+    targets
+}
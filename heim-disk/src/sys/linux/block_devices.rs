@@ -0,0 +1,122 @@
+use std::fs;
+use std::path::Path;
+
+use heim_common::prelude::*;
+use heim_common::sys::linux::read_value;
+use heim_common::units::{information, Information};
+use heim_runtime as rt;
+
+use super::counters::DISK_SECTOR_SIZE;
+
+#[derive(Debug)]
+pub struct BlockDevice {
+    name: String,
+    size: Information,
+    model: Option<String>,
+    rotational: Option<bool>,
+    removable: bool,
+    parent: Option<String>,
+}
+
+impl BlockDevice {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn size(&self) -> Information {
+        self.size
+    }
+
+    pub fn model(&self) -> Option<&str> {
+        self.model.as_deref()
+    }
+
+    pub fn is_rotational(&self) -> Option<bool> {
+        self.rotational
+    }
+
+    pub fn is_removable(&self) -> bool {
+        self.removable
+    }
+
+    pub fn parent(&self) -> Option<&str> {
+        self.parent.as_deref()
+    }
+}
+
+fn read_flag(path: &Path) -> Result<Option<bool>> {
+    Ok(read_value(path)?.map(|value| value == "1"))
+}
+
+fn read_size(path: &Path) -> Result<Information> {
+    // `size` file is always in the 512 bytes sectors,
+    // no matter what the real device sector size is.
+    let sectors = match read_value(path)? {
+        Some(value) => value.parse::<u64>()?,
+        None => return Err(Error::missing_key("size", format!("{}", path.display()))),
+    };
+
+    Ok(Information::new::<information::byte>(
+        sectors * DISK_SECTOR_SIZE,
+    ))
+}
+
+fn read_device(root: &Path, name: String) -> Result<Vec<BlockDevice>> {
+    let model = read_value(root.join("device/model"))?.filter(|model| !model.is_empty());
+    let rotational = read_flag(&root.join("queue/rotational"))?;
+    let removable = read_flag(&root.join("removable"))?.unwrap_or(false);
+
+    let mut partitions = Vec::new();
+    for entry in fs::read_dir(root).map_err(|e| Error::from(e).with_file(root))? {
+        let entry = entry?;
+        let path = entry.path();
+        // Partitions are represented as a sub-directories with the `partition` file in it
+        if !path.join("partition").exists() {
+            continue;
+        }
+
+        partitions.push(BlockDevice {
+            name: entry.file_name().to_string_lossy().into_owned(),
+            size: read_size(&path.join("size"))?,
+            model: None,
+            rotational,
+            removable,
+            parent: Some(name.clone()),
+        });
+    }
+    partitions.sort_by(|left, right| left.name.cmp(&right.name));
+
+    let device = BlockDevice {
+        size: read_size(&root.join("size"))?,
+        name,
+        model,
+        rotational,
+        removable,
+        parent: None,
+    };
+    partitions.insert(0, device);
+
+    Ok(partitions)
+}
+
+fn read_devices() -> Result<Vec<BlockDevice>> {
+    let root = rt::linux::sysfs_root().join("block");
+    let mut names = Vec::new();
+    for entry in fs::read_dir(&root).map_err(|e| Error::from(e).with_file(&root))? {
+        names.push(entry?.file_name().to_string_lossy().into_owned());
+    }
+    names.sort();
+
+    let mut acc = Vec::with_capacity(names.len());
+    for name in names {
+        acc.extend(read_device(&root.join(&name), name)?);
+    }
+
+    Ok(acc)
+}
+
+pub async fn block_devices() -> Result<impl Stream<Item = Result<BlockDevice>>> {
+    let devices = rt::spawn_blocking(read_devices).await?;
+
+    Ok(stream::iter(devices.into_iter().map(Ok)))
+}
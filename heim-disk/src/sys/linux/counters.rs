@@ -1,7 +1,10 @@
 use std::ffi::{CString, OsStr};
+use std::fs;
+use std::path::Path;
 use std::str::FromStr;
 
 use heim_common::prelude::*;
+use heim_common::sys::unix;
 use heim_common::units::{information, time, Information, Time};
 use heim_common::utils::iter::*;
 use heim_runtime as rt;
@@ -18,7 +21,14 @@ use heim_runtime as rt;
 // * https://github.com/giampaolo/psutil/issues/1305
 // * https://github.com/torvalds/linux/blob/4f671fe2f9523a1ea206f63fe60a7c7b3a56d5c7/include/linux/bio.h#L99
 // * https://lkml.org/lkml/2015/8/17/234
-const DISK_SECTOR_SIZE: u64 = 512;
+pub(crate) const DISK_SECTOR_SIZE: u64 = 512;
+
+/// Minimal amount of the stats fields in the `/proc/diskstats` line.
+const DISKSTATS_MIN_FIELDS: usize = 11;
+
+/// Amount of the stats fields in the `/proc/diskstats` line since Linux 4.18,
+/// which are including the discard values.
+const DISKSTATS_DISCARD_FIELDS: usize = 15;
 
 #[derive(Debug, Default)]
 pub struct IoCounters {
@@ -30,6 +40,9 @@ pub struct IoCounters {
     busy_time: Time,
     read_merged_count: u64,
     write_merged_count: u64,
+    discard_count: u64,
+    discard_merged_count: u64,
+    discard_bytes: Information,
 }
 
 impl IoCounters {
@@ -57,6 +70,26 @@ impl IoCounters {
         self.busy_time
     }
 
+    pub fn read_merged_count(&self) -> u64 {
+        self.read_merged_count
+    }
+
+    pub fn write_merged_count(&self) -> u64 {
+        self.write_merged_count
+    }
+
+    pub fn discard_count(&self) -> u64 {
+        self.discard_count
+    }
+
+    pub fn discard_merged_count(&self) -> u64 {
+        self.discard_merged_count
+    }
+
+    pub fn discard_bytes(&self) -> Information {
+        self.discard_bytes
+    }
+
     // Based on the sysstat code:
     // https://github.com/sysstat/sysstat/blob/1c711c1fd03ac638cfc1b25cdf700625c173fd2c/common.c#L200
     async fn is_storage_device(&self) -> Result<bool> {
@@ -66,10 +99,19 @@ impl IoCounters {
             self.name.replace("/", "!")
         ))?;
 
-        let result =
-            rt::spawn_blocking(move || unsafe { libc::access(path.as_ptr(), libc::F_OK) }).await;
+        let result = rt::spawn_blocking(move || {
+            unix::retry_eintr(|| {
+                if unsafe { libc::access(path.as_ptr(), libc::F_OK) } == 0 {
+                    Ok(())
+                } else {
+                    Err(Error::last_os_error().with_ffi("access"))
+                }
+            })
+        })
+        .await;
 
-        Ok(result == 0)
+        // Missing path means that it is a partition or a virtual device
+        Ok(result.is_ok())
     }
 }
 
@@ -77,14 +119,54 @@ impl FromStr for IoCounters {
     type Err = Error;
 
     // At the moment supports format used in Linux 2.6+,
-    // except ignoring discard values introduced in Linux 4.18.
+    // including discard values introduced in Linux 4.18,
+    // but ignoring flush values introduced in Linux 5.5.
+    //
+    // Line layout is `<major> <minor> <name> <stats...>`, where `<stats...>` are
+    // at least 11 integer fields (15 since Linux 4.18 and 17 for the recent kernels):
+    //
+    //  1. reads completed
+    //  2. reads merged
+    //  3. sectors read
+    //  4. time spent reading (ms)
+    //  5. writes completed
+    //  6. writes merged
+    //  7. sectors written
+    //  8. time spent writing (ms)
+    //  9. I/Os currently in progress
+    // 10. time spent doing I/Os (ms)
+    // 11. weighted time spent doing I/Os (ms)
+    // 12. discards completed
+    // 13. discards merged
+    // 14. sectors discarded
+    // 15. time spent discarding (ms)
+    //
+    // Discard values are left zeroed for the older kernels.
+    //
+    // Parsing is anchored on the leading `<major> <minor>` columns,
+    // and in order not to misalign the stats fields, device name is considered to be
+    // everything up to the trailing sequence of integer fields.
     //
     // https://www.kernel.org/doc/Documentation/iostats.txt
     // https://www.kernel.org/doc/Documentation/ABI/testing/procfs-diskstats
     fn from_str(s: &str) -> Result<IoCounters> {
-        let mut parts = s.split_whitespace().skip(2);
+        let mut parts = s.split_whitespace();
+        let _major: u64 = parts.try_parse_next()?;
+        let _minor: u64 = parts.try_parse_next()?;
+
+        let rest = parts.collect::<Vec<_>>();
+        let stats_offset = (1..rest.len())
+            .find(|offset| {
+                rest[*offset..]
+                    .iter()
+                    .all(|part| part.parse::<u64>().is_ok())
+            })
+            .filter(|offset| rest.len() - offset >= DISKSTATS_MIN_FIELDS)
+            .ok_or_else(|| Error::missing_key("stats", "/proc/diskstats"))?;
 
-        let name: String = parts.try_from_next()?;
+        let name = rest[..stats_offset].join(" ");
+        let has_discards = rest.len() - stats_offset >= DISKSTATS_DISCARD_FIELDS;
+        let mut parts = rest[stats_offset..].iter();
         let read_count = parts.try_parse_next()?;
         let read_merged_count = parts.try_parse_next()?;
         let read_bytes = parts
@@ -99,7 +181,19 @@ impl FromStr for IoCounters {
         let mut parts = parts.skip(2);
         let busy_time = parts
             .try_parse_next()
-            .map(|seconds: u64| Time::new::<time::second>(seconds as f64))?;
+            .map(|ms: u64| Time::new::<time::millisecond>(ms as f64))?;
+        let (discard_count, discard_merged_count, discard_bytes) = if has_discards {
+            let mut parts = parts.skip(1);
+            let count = parts.try_parse_next()?;
+            let merged_count = parts.try_parse_next()?;
+            let bytes = parts.try_parse_next().map(|bytes: u64| {
+                Information::new::<information::byte>(bytes * DISK_SECTOR_SIZE)
+            })?;
+
+            (count, merged_count, bytes)
+        } else {
+            (0, 0, Information::new::<information::byte>(0))
+        };
 
         Ok(IoCounters {
             name,
@@ -110,11 +204,16 @@ impl FromStr for IoCounters {
             write_merged_count,
             write_bytes,
             busy_time,
+            discard_count,
+            discard_merged_count,
+            discard_bytes,
         })
     }
 }
 
 pub async fn io_counters() -> Result<impl Stream<Item = Result<IoCounters>>> {
+    rt::linux::ensure_procfs().await?;
+
     let stream =
         rt::fs::read_lines_into::<_, _, Error>(rt::linux::procfs_root().join("diskstats")).await?;
 
@@ -133,3 +232,134 @@ pub async fn io_counters_physical() -> Result<impl Stream<Item = Result<IoCounte
 
     Ok(stream)
 }
+
+// Device paths (ex. `/dev/sda`) and the symlinks to them (ex. `/dev/mapper/root`)
+// are resolved to the kernel device names used in the `/proc/diskstats` file (ex. `dm-0`).
+fn kernel_device_name(name: &str) -> String {
+    if !name.starts_with('/') {
+        return name.to_string();
+    }
+
+    let path = fs::canonicalize(name).unwrap_or_else(|_| Path::new(name).to_path_buf());
+    match path.strip_prefix("/dev") {
+        Ok(relative) => relative.to_string_lossy().into_owned(),
+        Err(_) => path.to_string_lossy().into_owned(),
+    }
+}
+
+pub async fn io_counters_for(name: &str) -> Result<Option<IoCounters>> {
+    rt::linux::ensure_procfs().await?;
+
+    let name = name.to_string();
+    let name = rt::spawn_blocking(move || kernel_device_name(&name)).await;
+    let lines = rt::fs::read_lines(rt::linux::procfs_root().join("diskstats")).await?;
+    rt::pin!(lines);
+
+    while let Some(line) = lines.next().await {
+        let counters = IoCounters::from_str(&line?)?;
+        if counters.name == name {
+            return Ok(Some(counters));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use heim_common::units::{information, time};
+
+    use super::{kernel_device_name, IoCounters};
+
+    #[test]
+    fn test_parse_diskstats_line() {
+        let line =
+            "   8       0 sda 5019 1140 410094 2446 3296 2863 145280 4210 0 1000 6656 0 0 0 0";
+        let counters = IoCounters::from_str(line).unwrap();
+
+        assert_eq!(counters.device_name(), "sda");
+        assert_eq!(counters.read_count(), 5019);
+        assert_eq!(
+            counters.read_bytes().get::<information::byte>(),
+            410094 * 512
+        );
+        assert_eq!(counters.write_count(), 3296);
+        assert_eq!(
+            counters.write_bytes().get::<information::byte>(),
+            145280 * 512
+        );
+        assert!((counters.busy_time().get::<time::second>() - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_parse_discards() {
+        let line = "   8       0 sda 5019 1140 410094 2446 3296 2863 145280 4210 0 1000 6656 \
+            120 3 81920 44";
+        let counters = IoCounters::from_str(line).unwrap();
+
+        assert_eq!(counters.read_merged_count(), 1140);
+        assert_eq!(counters.write_merged_count(), 2863);
+        assert_eq!(counters.discard_count(), 120);
+        assert_eq!(counters.discard_merged_count(), 3);
+        assert_eq!(
+            counters.discard_bytes().get::<information::byte>(),
+            81920 * 512
+        );
+    }
+
+    #[test]
+    fn test_parse_without_discards() {
+        let line = "   8       0 sda 5019 1140 410094 2446 3296 2863 145280 4210 0 1000 6656";
+        let counters = IoCounters::from_str(line).unwrap();
+
+        assert_eq!(counters.write_count(), 3296);
+        assert_eq!(counters.discard_count(), 0);
+        assert_eq!(counters.discard_merged_count(), 0);
+        assert_eq!(counters.discard_bytes().get::<information::byte>(), 0);
+    }
+
+    #[test]
+    fn test_parse_device_mapper_line() {
+        let line = " 253       0 dm-0 12 0 136 4 34 0 272 8 0 16 12";
+        let counters = IoCounters::from_str(line).unwrap();
+
+        assert_eq!(counters.device_name(), "dm-0");
+        assert_eq!(counters.read_count(), 12);
+        assert_eq!(counters.write_count(), 34);
+        assert_eq!(counters.read_bytes().get::<information::byte>(), 136 * 512);
+        assert_eq!(counters.write_bytes().get::<information::byte>(), 272 * 512);
+    }
+
+    #[test]
+    fn test_parse_name_with_spaces() {
+        let line = " 253       1 vg0 data 12 0 136 4 34 0 272 8 0 16 12 0 0 0 0 0 0";
+        let counters = IoCounters::from_str(line).unwrap();
+
+        assert_eq!(counters.device_name(), "vg0 data");
+        assert_eq!(counters.read_count(), 12);
+        assert_eq!(counters.write_count(), 34);
+    }
+
+    #[test]
+    fn test_parse_truncated_line() {
+        assert!(IoCounters::from_str("   8       0 sda 5019 1140 410094").is_err());
+        assert!(IoCounters::from_str(
+            "sda 5019 1140 410094 2446 3296 2863 145280 4210 0 1000 6656"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_kernel_device_name() {
+        assert_eq!(kernel_device_name("sda"), "sda");
+        assert_eq!(kernel_device_name("cciss/c0d0"), "cciss/c0d0");
+        // Missing device paths are not resolved, but `/dev/` prefix is stripped anyway
+        assert_eq!(kernel_device_name("/dev/heim-missing0"), "heim-missing0");
+        assert_eq!(
+            kernel_device_name("/dev/cciss/heim-missing0"),
+            "cciss/heim-missing0"
+        );
+    }
+}
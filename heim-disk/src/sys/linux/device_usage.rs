@@ -0,0 +1,77 @@
+use std::io;
+
+use heim_common::prelude::*;
+use heim_common::units::{information, Information};
+use heim_runtime as rt;
+
+use super::bindings;
+use super::counters::DISK_SECTOR_SIZE;
+
+#[derive(Debug)]
+pub struct DeviceUsage {
+    provisioned: Information,
+    allocated: Information,
+}
+
+impl DeviceUsage {
+    pub fn provisioned(&self) -> Information {
+        self.provisioned
+    }
+
+    pub fn allocated(&self) -> Information {
+        self.allocated
+    }
+}
+
+// Parses `thin` target status line, which is
+// `<nr mapped sectors> <highest mapped sector>` or `Fail`.
+//
+// See `Documentation/admin-guide/device-mapper/thin-provisioning.rst` in the Linux sources.
+fn parse_mapped_sectors(status: &str) -> Result<u64> {
+    let value = status.split_whitespace().next().unwrap_or("");
+    match value.parse::<u64>() {
+        Ok(sectors) => Ok(sectors),
+        Err(e) => {
+            Err(Error::from(e).with_message(format!("Unable to parse thin status {:?}", status)))
+        }
+    }
+}
+
+pub async fn device_usage(name: &str) -> Result<Option<DeviceUsage>> {
+    let root = rt::linux::sysfs_root().join("block").join(name);
+
+    let dm_name = match rt::fs::read_first_line(root.join("dm/name")).await {
+        Ok(dm_name) => dm_name,
+        // Not a device-mapper device at all
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+
+    let targets = rt::spawn_blocking(move || bindings::table_status(dm_name.trim())).await?;
+    if targets.is_empty() || targets.iter().any(|target| target.target_type != "thin") {
+        return Ok(None);
+    }
+
+    let mut provisioned = 0;
+    let mut allocated = 0;
+    for target in targets {
+        provisioned += target.length;
+        allocated += parse_mapped_sectors(&target.status)?;
+    }
+
+    Ok(Some(DeviceUsage {
+        provisioned: Information::new::<information::byte>(provisioned * DISK_SECTOR_SIZE),
+        allocated: Information::new::<information::byte>(allocated * DISK_SECTOR_SIZE),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_mapped_sectors;
+
+    #[test]
+    fn test_parse_mapped_sectors() {
+        assert_eq!(parse_mapped_sectors("2048 4095").unwrap(), 2048);
+        assert!(parse_mapped_sectors("Fail").is_err());
+    }
+}
@@ -1,5 +1,10 @@
+mod bindings;
+mod block_devices;
 mod counters;
+mod device_usage;
 mod partitions;
 
+pub use self::block_devices::*;
 pub use self::counters::*;
+pub use self::device_usage::*;
 pub use self::partitions::*;
@@ -3,9 +3,10 @@ use std::ffi::OsStr;
 use std::fs;
 use std::io::{self, BufRead};
 use std::path::{Path, PathBuf};
-use std::str::FromStr;
+use std::str::{self, FromStr};
 
 use heim_common::prelude::*;
+use heim_common::sys::linux::read_value;
 use heim_runtime as rt;
 
 use crate::FileSystem;
@@ -36,6 +37,82 @@ impl Partition {
     pub fn options(&self) -> &str {
         &self.options
     }
+
+    pub async fn is_encrypted(&self) -> Result<bool> {
+        let device = match self.device {
+            Some(ref device) if device.starts_with("/dev/") => PathBuf::from(device),
+            _ => return Ok(false),
+        };
+
+        rt::spawn_blocking(move || {
+            // `/dev/mapper/*` entries are symlinks to the `/dev/dm-*` devices
+            let device = match fs::canonicalize(device) {
+                Ok(path) => path,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(false),
+                Err(e) => return Err(e.into()),
+            };
+            match device.file_name() {
+                Some(name) => is_crypt_device(name),
+                None => Ok(false),
+            }
+        })
+        .await
+    }
+}
+
+// Walks the device-mapper stack down from the `name` block device,
+// looking for the dm-crypt targets (which have the `CRYPT-` prefix in their UUIDs).
+//
+// Devices like LVM volumes on top of LUKS are encrypted too,
+// so the `slaves` directory is traversed recursively.
+fn is_crypt_device(name: &OsStr) -> Result<bool> {
+    let root = rt::linux::sysfs_root().join("class/block").join(name);
+
+    match read_value(root.join("dm/uuid"))? {
+        Some(uuid) if uuid.starts_with("CRYPT-") => return Ok(true),
+        Some(..) => {}
+        None => return Ok(false),
+    }
+
+    let slaves = match fs::read_dir(root.join("slaves")) {
+        Ok(slaves) => slaves,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(e.into()),
+    };
+    for slave in slaves {
+        if is_crypt_device(&slave?.file_name())? {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+// Whitespaces and backslashes in `/proc/mounts` fields are escaped as octal codes,
+// ex. `/mnt/my\040disk` stands for the `/mnt/my disk` mount point
+fn unescape(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut acc = Vec::with_capacity(bytes.len());
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let code = bytes
+            .get(idx + 1..idx + 4)
+            .filter(|_| bytes[idx] == b'\\')
+            .and_then(|digits| str::from_utf8(digits).ok())
+            .and_then(|digits| u8::from_str_radix(digits, 8).ok());
+        match code {
+            Some(code) => {
+                acc.push(code);
+                idx += 4;
+            }
+            None => {
+                acc.push(bytes[idx]);
+                idx += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&acc).into_owned()
 }
 
 impl FromStr for Partition {
@@ -47,7 +124,7 @@ impl FromStr for Partition {
         let mount_root = rt::linux::procfs_root().join("mounts");
         let device = match parts.next() {
             Some(device) if device == "none" => None,
-            Some(device) => Some(device.to_string()),
+            Some(device) => Some(unescape(device)),
             None => {
                 return Err(Error::missing_key(
                     "device",
@@ -56,7 +133,7 @@ impl FromStr for Partition {
             }
         };
         let mount_point = match parts.next() {
-            Some(point) => PathBuf::from(point),
+            Some(point) => PathBuf::from(unescape(point)),
             None => {
                 return Err(Error::missing_key(
                     "mount point",
@@ -74,7 +151,7 @@ impl FromStr for Partition {
             }
         };
         let options = match parts.next() {
-            Some(opts) => opts.to_string(),
+            Some(opts) => unescape(opts),
             None => {
                 return Err(Error::missing_key(
                     "options",
@@ -124,6 +201,8 @@ async fn known_filesystems() -> Result<HashSet<FileSystem>> {
 }
 
 pub async fn partitions() -> Result<impl Stream<Item = Result<Partition>>> {
+    rt::linux::ensure_procfs().await?;
+
     let lines = rt::fs::read_lines(rt::linux::procfs_root().join("mounts")).await?;
     let stream = lines
         .map_err(Error::from)
@@ -148,3 +227,29 @@ pub async fn partitions_physical() -> Result<impl Stream<Item = Result<Partition
 
     Ok(stream)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use super::{unescape, Partition};
+
+    #[test]
+    fn test_unescape() {
+        assert_eq!(unescape("/mnt/my\\040disk"), "/mnt/my disk");
+        assert_eq!(unescape("tab\\011and\\134slash"), "tab\tand\\slash");
+        assert_eq!(unescape("/dev/sda1"), "/dev/sda1");
+        assert_eq!(unescape("trailing\\04"), "trailing\\04");
+    }
+
+    #[test]
+    fn test_parse_escaped_line() {
+        let line = "/dev/sdb1 /media/usb\\040stick vfat rw,relatime,fmask=0022 0 0";
+        let partition = Partition::from_str(line).unwrap();
+
+        assert_eq!(partition.device().unwrap(), "/dev/sdb1");
+        assert_eq!(partition.mount_point(), Path::new("/media/usb stick"));
+        assert_eq!(partition.options(), "rw,relatime,fmask=0022");
+    }
+}
@@ -0,0 +1,107 @@
+use heim_common::prelude::*;
+use heim_common::sys::macos::iokit::{self, DictionaryProps};
+use heim_common::units::{information, Information};
+
+#[derive(Debug)]
+pub struct BlockDevice {
+    name: String,
+    size: Information,
+    model: Option<String>,
+    rotational: Option<bool>,
+    removable: bool,
+    parent: Option<String>,
+}
+
+impl BlockDevice {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn size(&self) -> Information {
+        self.size
+    }
+
+    pub fn model(&self) -> Option<&str> {
+        self.model.as_deref()
+    }
+
+    pub fn is_rotational(&self) -> Option<bool> {
+        self.rotational
+    }
+
+    pub fn is_removable(&self) -> bool {
+        self.removable
+    }
+
+    pub fn parent(&self) -> Option<&str> {
+        self.parent.as_deref()
+    }
+}
+
+// Partitions are named as `disk0s1`, where `disk0` is the whole disk name
+fn parent_name(name: &str) -> Option<String> {
+    let offset = name.rfind('s')?;
+    let (parent, suffix) = name.split_at(offset);
+    if parent.len() > "disk".len() && suffix[1..].chars().all(|c| c.is_ascii_digit()) {
+        Some(parent.to_string())
+    } else {
+        None
+    }
+}
+
+// `IOMedia` -> `IOBlockStorageDriver` -> `IOBlockStorageDevice`
+fn storage_device(media: &iokit::IoObject) -> Result<Option<iokit::IoObject>> {
+    let driver = media.parent(b"IOService\0")?;
+    if !driver.conforms_to(b"IOBlockStorageDriver\0") {
+        return Ok(None);
+    }
+    let device = driver.parent(b"IOService\0")?;
+    if !device.conforms_to(b"IOBlockStorageDevice\0") {
+        return Ok(None);
+    }
+
+    Ok(Some(device))
+}
+
+fn block_device(media: iokit::IoObject) -> Result<BlockDevice> {
+    let props = media.properties()?;
+    let name = props.get_string("BSD Name")?;
+    let size = props.get_i64("Size")? as u64;
+    let removable = props.get_bool("Removable")?;
+
+    let (parent, model, rotational) = if props.get_bool("Whole")? {
+        let characteristics = match storage_device(&media)? {
+            Some(device) => device.properties()?.get_dict("Device Characteristics").ok(),
+            None => None,
+        };
+        let model = characteristics
+            .as_ref()
+            .and_then(|dict| dict.get_string("Product Name").ok())
+            .map(|model| model.trim().to_string());
+        let rotational = characteristics
+            .as_ref()
+            .and_then(|dict| dict.get_string("Medium Type").ok())
+            .map(|medium| medium == "Rotational");
+
+        (None, model, rotational)
+    } else {
+        (parent_name(&name), None, None)
+    };
+
+    Ok(BlockDevice {
+        name,
+        size: Information::new::<information::byte>(size),
+        model,
+        rotational,
+        removable,
+        parent,
+    })
+}
+
+pub async fn block_devices() -> Result<impl Stream<Item = Result<BlockDevice>>> {
+    let port = iokit::IoMasterPort::new()?;
+
+    let iter = port.get_services(b"IOMedia\0")?.map(block_device);
+
+    Ok(stream::iter(iter))
+}
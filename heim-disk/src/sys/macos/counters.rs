@@ -3,6 +3,7 @@ use std::ffi::OsStr;
 use heim_common::prelude::*;
 use heim_common::sys::macos::iokit::{self, DictionaryProps};
 use heim_common::units::{information, time, Information, Time};
+use heim_runtime as rt;
 
 #[derive(Debug)]
 pub struct IoCounters {
@@ -93,3 +94,14 @@ pub async fn io_counters_physical() -> Result<impl Stream<Item = Result<IoCounte
 
     Ok(stream)
 }
+
+pub async fn io_counters_for(name: &str) -> Result<Option<IoCounters>> {
+    // Device paths (ex. `/dev/disk0`) are matched by the BSD device name
+    let name = OsStr::new(name.strip_prefix("/dev/").unwrap_or(name));
+    let counters = io_counters()
+        .await?
+        .try_filter(|counter| future::ready(counter.device_name() == name));
+    rt::pin!(counters);
+
+    counters.try_next().await
+}
@@ -1,3 +1,5 @@
+mod block_devices;
 mod counters;
 
+pub use self::block_devices::*;
 pub use self::counters::*;
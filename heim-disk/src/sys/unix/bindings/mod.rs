@@ -1,17 +1,39 @@
+use std::ffi::CString;
 use std::mem;
 use std::ptr;
 
+use core_foundation::base::{kCFAllocatorDefault, CFAllocatorRef, CFRelease, CFType, TCFType};
+use core_foundation::dictionary::{CFDictionary, CFDictionaryRef};
+use core_foundation::string::CFString;
+
 use heim_common::prelude::*;
+use heim_common::sys::macos::iokit::DictionaryProps;
 
 #[allow(unused)]
 pub const MNT_WAIT: libc::c_int = 1;
 pub const MNT_NOWAIT: libc::c_int = 2;
 
+#[allow(non_camel_case_types)]
+type DASessionRef = *const libc::c_void;
+#[allow(non_camel_case_types)]
+type DADiskRef = *const libc::c_void;
+
 extern "C" {
     fn getfsstat64(buf: *mut libc::statfs, bufsize: libc::c_int, flags: libc::c_int)
         -> libc::c_int;
 }
 
+#[link(name = "DiskArbitration", kind = "framework")]
+extern "C" {
+    fn DASessionCreate(allocator: CFAllocatorRef) -> DASessionRef;
+    fn DADiskCreateFromBSDName(
+        allocator: CFAllocatorRef,
+        session: DASessionRef,
+        name: *const libc::c_char,
+    ) -> DADiskRef;
+    fn DADiskCopyDescription(disk: DADiskRef) -> CFDictionaryRef;
+}
+
 // TODO: Since `MNT_NOWAIT` might return inconsistent data (see `getfsstat(2)`)
 // it might be a good idea (maybe?) to wrap it into a `blocking` call
 // and switch to the `MNT_WAIT` mode?
@@ -42,3 +64,32 @@ pub fn mounts() -> Result<Vec<libc::statfs>> {
 
     Ok(mounts)
 }
+
+/// Checks if the disk with `bsd_name` name (ex. `disk1s1`) is encrypted
+/// (FileVault, encrypted APFS or CoreStorage volumes).
+pub fn is_encrypted(bsd_name: &str) -> Result<bool> {
+    let name = CString::new(bsd_name)?;
+
+    let description = unsafe {
+        let session = DASessionCreate(kCFAllocatorDefault);
+        if session.is_null() {
+            return Err(Error::last_os_error().with_ffi("DASessionCreate"));
+        }
+        let disk = DADiskCreateFromBSDName(kCFAllocatorDefault, session, name.as_ptr());
+        if disk.is_null() {
+            CFRelease(session);
+            return Err(Error::last_os_error().with_ffi("DADiskCreateFromBSDName"));
+        }
+        let description = DADiskCopyDescription(disk);
+        CFRelease(disk);
+        CFRelease(session);
+        if description.is_null() {
+            return Err(Error::last_os_error().with_ffi("DADiskCopyDescription"));
+        }
+
+        CFDictionary::<CFString, CFType>::wrap_under_create_rule(description)
+    };
+
+    // Key is missing for the disks which are not encrypted at all
+    Ok(description.get_bool("DAMediaEncrypted").unwrap_or(false))
+}
@@ -31,6 +31,14 @@ impl Partition {
     pub fn raw_flags(&self) -> u32 {
         self.flags
     }
+
+    pub async fn is_encrypted(&self) -> Result<bool> {
+        // Pseudo filesystems like `devfs` or `map auto_home` are not backed by any disk
+        match self.device.strip_prefix("/dev/") {
+            Some(bsd_name) => bindings::is_encrypted(bsd_name),
+            None => Ok(false),
+        }
+    }
 }
 
 // TODO: Since `from` may fail in fact, replace it with a `try_from`
@@ -34,6 +34,13 @@ impl Usage {
         Information::new::<information::byte>(value)
     }
 
+    pub fn reserved(&self) -> Information {
+        // `f_bfree` includes the blocks reserved for the privileged users, while `f_bavail` does not
+        let reserved = u64::from(self.0.f_bfree).saturating_sub(u64::from(self.0.f_bavail));
+
+        Information::new::<information::byte>(reserved * u64::from(self.0.f_frsize))
+    }
+
     pub fn ratio(&self) -> Ratio {
         // FIXME: Possible value truncation while casting into f64.
         // Lucky us, it is a 2019 and we are good for the next couple of decades
@@ -2,10 +2,12 @@ mod drive;
 mod drive_type;
 mod drives;
 mod perf;
+mod physical_drive;
 mod volumes;
 
 pub use self::drive::Drive;
 pub use self::drives::Drives;
 pub use self::perf::disk_performance;
+pub use self::physical_drive::{physical_drive, PhysicalDrive, MAX_PHYSICAL_DRIVES};
 pub use self::volumes::Volumes;
 pub use crate::os::windows::DriveType;
@@ -0,0 +1,212 @@
+use std::ffi::{CStr, OsStr};
+use std::mem;
+use std::os::raw::c_char;
+use std::os::windows::ffi::OsStrExt;
+use std::ptr;
+
+use winapi::shared::{minwindef, ntdef, winerror};
+use winapi::um::{fileapi, handleapi, ioapiset, winioctl, winnt};
+
+use heim_common::prelude::*;
+
+const ERROR_FILE_NOT_FOUND: i32 = winerror::ERROR_FILE_NOT_FOUND as i32;
+const ERROR_INVALID_FUNCTION: i32 = winerror::ERROR_INVALID_FUNCTION as i32;
+const ERROR_NOT_SUPPORTED: i32 = winerror::ERROR_NOT_SUPPORTED as i32;
+
+/// There is no API to enumerate physical drives directly,
+/// so the `\\.\PhysicalDriveN` paths are probed up to this number.
+pub const MAX_PHYSICAL_DRIVES: u32 = 64;
+
+// `u64` items are used to keep buffer properly aligned for the descriptor struct
+type DescriptorBuffer = [u64; 128];
+
+// `DISK_GEOMETRY_EX` is followed by the variable-sized partition and detection info,
+// buffer is large enough to fit them too
+type GeometryBuffer = [u64; 32];
+
+// These structs are missing in `winapi`,
+// see https://docs.microsoft.com/en-us/windows/win32/api/winioctl/ns-winioctl-storage_device_descriptor
+#[allow(non_snake_case, dead_code)]
+#[repr(C)]
+struct STORAGE_DEVICE_DESCRIPTOR {
+    Version: minwindef::DWORD,
+    Size: minwindef::DWORD,
+    DeviceType: minwindef::BYTE,
+    DeviceTypeModifier: minwindef::BYTE,
+    RemovableMedia: ntdef::BOOLEAN,
+    CommandQueueing: ntdef::BOOLEAN,
+    VendorIdOffset: minwindef::DWORD,
+    ProductIdOffset: minwindef::DWORD,
+    ProductRevisionOffset: minwindef::DWORD,
+    SerialNumberOffset: minwindef::DWORD,
+    BusType: minwindef::DWORD,
+    RawPropertiesLength: minwindef::DWORD,
+    RawDeviceProperties: [minwindef::BYTE; 1],
+}
+
+// https://docs.microsoft.com/en-us/windows/win32/api/winioctl/ns-winioctl-device_seek_penalty_descriptor
+#[allow(non_snake_case, dead_code)]
+#[repr(C)]
+#[derive(Default)]
+struct DEVICE_SEEK_PENALTY_DESCRIPTOR {
+    Version: minwindef::DWORD,
+    Size: minwindef::DWORD,
+    IncursSeekPenalty: ntdef::BOOLEAN,
+}
+
+#[derive(Debug)]
+pub struct PhysicalDrive {
+    pub path: String,
+    pub length: u64,
+    pub product_id: Option<String>,
+    pub removable: bool,
+    pub seek_penalty: Option<bool>,
+}
+
+struct Handle(winnt::HANDLE);
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        let _ = unsafe { handleapi::CloseHandle(self.0) };
+    }
+}
+
+impl Handle {
+    #[allow(trivial_casts)]
+    fn ioctl<I, O>(
+        &self,
+        code: minwindef::DWORD,
+        input: Option<&mut I>,
+        output: &mut O,
+    ) -> Result<()> {
+        let (input_ptr, input_size) = match input {
+            Some(input) => (input as *mut I as minwindef::LPVOID, mem::size_of::<I>()),
+            None => (ptr::null_mut(), 0),
+        };
+        let mut bytes_returned: minwindef::DWORD = 0;
+        let result = unsafe {
+            ioapiset::DeviceIoControl(
+                self.0,
+                code,
+                input_ptr,
+                input_size as minwindef::DWORD,
+                output as *mut O as minwindef::LPVOID,
+                mem::size_of::<O>() as minwindef::DWORD,
+                &mut bytes_returned,
+                ptr::null_mut(),
+            )
+        };
+
+        if result == 0 {
+            Err(Error::last_os_error().with_ffi("DeviceIoControl"))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn query_property<O>(
+        &self,
+        property: winioctl::STORAGE_PROPERTY_ID,
+        output: &mut O,
+    ) -> Result<()> {
+        let mut query = winioctl::STORAGE_PROPERTY_QUERY {
+            PropertyId: property,
+            QueryType: winioctl::PropertyStandardQuery,
+            AdditionalParameters: [0],
+        };
+
+        self.ioctl(
+            winioctl::IOCTL_STORAGE_QUERY_PROPERTY,
+            Some(&mut query),
+            output,
+        )
+    }
+}
+
+fn is_unsupported(e: &Error) -> bool {
+    matches!(
+        e.raw_os_error(),
+        Some(ERROR_INVALID_FUNCTION) | Some(ERROR_NOT_SUPPORTED)
+    )
+}
+
+/// Fetches `\\.\PhysicalDrive{number}` drive information.
+///
+/// ## Returns
+///
+/// `Ok(None)` is returned if there is no drive with this number.
+pub fn physical_drive(number: u32) -> Result<Option<PhysicalDrive>> {
+    let path = format!("\\\\.\\PhysicalDrive{}", number);
+    let wide_path = OsStr::new(&path)
+        .encode_wide()
+        .chain(Some(0x0000))
+        .collect::<Vec<_>>();
+
+    // Zero access rights are enough for the `FILE_ANY_ACCESS` ioctls used below
+    // and they do not require the elevated privileges.
+    // `IOCTL_DISK_GET_LENGTH_INFO` requires `FILE_READ_ACCESS`, hence the disk size
+    // is taken from the drive geometry instead.
+    let handle = unsafe {
+        fileapi::CreateFileW(
+            wide_path.as_ptr(),
+            0,
+            winnt::FILE_SHARE_READ | winnt::FILE_SHARE_WRITE,
+            ptr::null_mut(),
+            fileapi::OPEN_EXISTING,
+            0,
+            ptr::null_mut(),
+        )
+    };
+    if handle == handleapi::INVALID_HANDLE_VALUE {
+        let e = Error::last_os_error();
+        return match e.raw_os_error() {
+            Some(ERROR_FILE_NOT_FOUND) => Ok(None),
+            _ => Err(e.with_ffi("CreateFileW")),
+        };
+    }
+    let handle = Handle(handle);
+
+    let mut geometry: GeometryBuffer = [0; 32];
+    handle.ioctl::<(), _>(
+        winioctl::IOCTL_DISK_GET_DRIVE_GEOMETRY_EX,
+        None,
+        &mut geometry,
+    )?;
+    let length = unsafe {
+        let geometry = &*(geometry.as_ptr() as *const winioctl::DISK_GEOMETRY_EX);
+        *geometry.DiskSize.QuadPart() as u64
+    };
+
+    let mut buffer: DescriptorBuffer = [0; 128];
+    handle.query_property(winioctl::StorageDeviceProperty, &mut buffer)?;
+    let (product_id, removable) = unsafe {
+        let base = buffer.as_ptr() as *const u8;
+        let descriptor = &*(base as *const STORAGE_DEVICE_DESCRIPTOR);
+        let offset = descriptor.ProductIdOffset as usize;
+        let product_id = if offset > 0 && offset < mem::size_of::<DescriptorBuffer>() {
+            let value = CStr::from_ptr(base.add(offset) as *const c_char);
+            Some(value.to_string_lossy().trim().to_string()).filter(|value| !value.is_empty())
+        } else {
+            None
+        };
+
+        (product_id, descriptor.RemovableMedia != 0)
+    };
+
+    let mut penalty = DEVICE_SEEK_PENALTY_DESCRIPTOR::default();
+    let seek_penalty =
+        match handle.query_property(winioctl::StorageDeviceSeekPenaltyProperty, &mut penalty) {
+            Ok(()) => Some(penalty.IncursSeekPenalty != 0),
+            // Some drivers (ex. for USB sticks) are not providing this property
+            Err(e) if is_unsupported(&e) => None,
+            Err(e) => return Err(e),
+        };
+
+    Ok(Some(PhysicalDrive {
+        path,
+        length,
+        product_id,
+        removable,
+        seek_penalty,
+    }))
+}
@@ -0,0 +1,52 @@
+use heim_common::prelude::*;
+use heim_common::units::{information, Information};
+use heim_runtime as rt;
+
+use super::bindings;
+
+#[derive(Debug)]
+pub struct BlockDevice(bindings::PhysicalDrive);
+
+impl BlockDevice {
+    pub fn name(&self) -> &str {
+        &self.0.path
+    }
+
+    pub fn size(&self) -> Information {
+        Information::new::<information::byte>(self.0.length)
+    }
+
+    pub fn model(&self) -> Option<&str> {
+        self.0.product_id.as_deref()
+    }
+
+    pub fn is_rotational(&self) -> Option<bool> {
+        // Only rotational drives are incurring the seek penalty
+        self.0.seek_penalty
+    }
+
+    pub fn is_removable(&self) -> bool {
+        self.0.removable
+    }
+
+    pub fn parent(&self) -> Option<&str> {
+        // TODO: Partitions can be fetched via the `IOCTL_DISK_GET_DRIVE_LAYOUT_EX`
+        None
+    }
+}
+
+pub async fn block_devices() -> Result<impl Stream<Item = Result<BlockDevice>>> {
+    let drives = rt::spawn_blocking(|| {
+        let mut acc = Vec::new();
+        for number in 0..bindings::MAX_PHYSICAL_DRIVES {
+            if let Some(drive) = bindings::physical_drive(number)? {
+                acc.push(BlockDevice(drive));
+            }
+        }
+
+        Ok::<_, Error>(acc)
+    })
+    .await?;
+
+    Ok(stream::iter(drives.into_iter().map(Ok)))
+}
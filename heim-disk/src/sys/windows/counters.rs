@@ -3,6 +3,7 @@ use std::path::{Path, PathBuf};
 
 use heim_common::prelude::*;
 use heim_common::units::{information, time, Information, Time};
+use heim_runtime as rt;
 
 use super::bindings;
 
@@ -96,3 +97,13 @@ pub async fn io_counters_physical() -> Result<impl Stream<Item = Result<IoCounte
 
     Ok(stream::iter(iter))
 }
+
+pub async fn io_counters_for(name: &str) -> Result<Option<IoCounters>> {
+    let name = OsStr::new(name);
+    let counters = io_counters()
+        .await?
+        .try_filter(|counter| future::ready(counter.device_name() == name));
+    rt::pin!(counters);
+
+    counters.try_next().await
+}
@@ -1,9 +1,11 @@
+mod block_devices;
 mod counters;
 mod partitions;
 mod usage;
 
 mod bindings;
 
+pub use self::block_devices::*;
 pub use self::counters::*;
 pub use self::partitions::*;
 pub use self::usage::*;
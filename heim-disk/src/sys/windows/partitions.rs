@@ -1,6 +1,7 @@
 use std::ffi::{OsStr, OsString};
 use std::path::{Path, PathBuf};
 
+use heim_common::not_supported;
 use heim_common::prelude::*;
 
 use super::bindings;
@@ -38,6 +39,11 @@ impl Partition {
     pub fn drive_type(&self) -> Option<DriveType> {
         self.drive_type
     }
+
+    pub async fn is_encrypted(&self) -> Result<bool> {
+        // TODO: BitLocker status can be fetched via the `Win32_EncryptableVolume` WMI class
+        Err(not_supported("BitLocker volumes detection"))
+    }
 }
 
 pub async fn partitions() -> Result<impl Stream<Item = Result<Partition>>> {
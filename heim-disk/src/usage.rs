@@ -1,8 +1,10 @@
 use std::fmt;
 use std::path::Path;
+use std::time::Duration;
 
 use heim_common::prelude::*;
 use heim_common::units::{Information, Ratio};
+use heim_runtime as rt;
 
 use crate::sys;
 
@@ -28,7 +30,14 @@ impl Usage {
         self.as_ref().used()
     }
 
-    /// Returns free information about used in partition.
+    /// Returns free information amount in partition.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Unix systems this is an amount available for the unprivileged users,
+    /// see [UsageExt::reserved] for the space reserved for the privileged ones.
+    ///
+    /// [UsageExt::reserved]: ./os/unix/trait.UsageExt.html#tymethod.reserved
     pub fn free(&self) -> Information {
         self.as_ref().free()
     }
@@ -50,6 +59,25 @@ impl fmt::Debug for Usage {
     }
 }
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde_crate::Serialize for Usage {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde_crate::Serializer,
+    {
+        use heim_common::serialize::Quantity;
+        use serde_crate::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Usage", 4)?;
+        state.serialize_field("total", &Quantity(&self.total()))?;
+        state.serialize_field("used", &Quantity(&self.used()))?;
+        state.serialize_field("free", &Quantity(&self.free()))?;
+        state.serialize_field("ratio", &Quantity(&self.ratio()))?;
+        state.end()
+    }
+}
+
 /// Returns disk [Usage] statistics about the partition which contains the given `path`.
 ///
 /// [Usage]: ./struct.Usage.html
@@ -59,3 +87,43 @@ where
 {
     sys::usage(path).await.map(Into::into)
 }
+
+/// Returns a stream which yields disk [Usage] statistics
+/// about the partition which contains the given `path` every `interval`.
+///
+/// First value is yielded immediately, and the stream never ends.
+/// Failed fetch (ex. network filesystem is not responding for a moment)
+/// is yielded as an error without terminating the stream,
+/// so the last successfully fetched value can be used until the next one.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// # use std::time::Duration;
+/// # use heim_common::units::ratio;
+/// # use heim_common::prelude::*;
+/// # use heim_disk::usage_watch;
+/// #
+/// # #[heim_derive::main]
+/// # async fn main() -> Result<()> {
+/// let mut watch = usage_watch("/", Duration::from_secs(60)).boxed_local();
+/// while let Some(usage) = watch.next().await {
+///     if usage?.ratio().get::<ratio::percent>() > 90.0 {
+///         println!("Less than 10 % of disk space is left");
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [Usage]: ./struct.Usage.html
+pub fn usage_watch<T>(path: T, interval: Duration) -> impl Stream<Item = Result<Usage>>
+where
+    T: AsRef<Path>,
+{
+    let path = path.as_ref().to_path_buf();
+
+    stream::once(future::ready(()))
+        .chain(rt::time::interval(interval))
+        .then(move |_| usage(path.clone()))
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "blocking")]
+
+use heim_common::prelude::*;
+use heim_disk as disk;
+
+#[test]
+fn blocking_partitions() {
+    let blocking = disk::blocking::partitions().unwrap();
+    let async_value =
+        smol::block_on(async { disk::partitions().await?.try_collect::<Vec<_>>().await }).unwrap();
+
+    let mount_points = |partitions: &[disk::Partition]| {
+        partitions
+            .iter()
+            .map(|partition| partition.mount_point().to_path_buf())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(mount_points(&blocking), mount_points(&async_value));
+}
+
+#[test]
+fn blocking_usage() {
+    let blocking = disk::blocking::usage("/").unwrap();
+    let async_value = smol::block_on(disk::usage("/")).unwrap();
+
+    assert_eq!(blocking.total(), async_value.total());
+}
+
+#[test]
+fn blocking_io_counters() {
+    let _ = disk::blocking::io_counters().unwrap();
+}
+
+#[test]
+fn blocking_io_counters_for() {
+    assert!(disk::blocking::io_counters_for("heim-missing-device")
+        .unwrap()
+        .is_none());
+}
@@ -2,6 +2,7 @@ use std::error::Error;
 
 use futures::StreamExt;
 
+use heim_common::units::{time, Time};
 use heim_disk as disk;
 
 #[heim_derive::test]
@@ -15,6 +16,14 @@ async fn smoke_partitions() -> Result<(), Box<dyn Error>> {
         let _ = part.mount_point();
         let _ = part.file_system();
 
+        #[cfg(target_os = "linux")]
+        {
+            use heim_disk::os::linux::PartitionExt;
+
+            let _ = part.options();
+            let _ = part.flags();
+        }
+
         #[cfg(target_os = "macos")]
         {
             use heim_disk::os::macos::PartitionExt;
@@ -64,6 +73,35 @@ async fn smoke_partitions_physical() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[heim_derive::test]
+async fn smoke_partitions_with_usage() -> Result<(), Box<dyn Error>> {
+    let partitions = disk::partitions_with_usage().await?;
+    futures::pin_mut!(partitions);
+    while let Some(part) = partitions.next().await {
+        let part = match part {
+            Ok(part) => part,
+            // Some mount points might be unavailable for the current user
+            Err(..) => continue,
+        };
+
+        assert!(part.total().is_some());
+    }
+
+    Ok(())
+}
+
+#[heim_derive::test]
+#[cfg(not(target_os = "windows"))]
+async fn smoke_partitions_encryption() -> Result<(), Box<dyn Error>> {
+    let partitions = disk::partitions_physical().await?;
+    futures::pin_mut!(partitions);
+    while let Some(part) = partitions.next().await {
+        let _ = part?.is_encrypted().await?;
+    }
+
+    Ok(())
+}
+
 #[heim_derive::test]
 async fn smoke_usage() -> Result<(), Box<dyn Error>> {
     let usage = disk::usage("/").await?;
@@ -78,6 +116,21 @@ async fn smoke_usage() -> Result<(), Box<dyn Error>> {
         use heim_disk::os::unix::UsageExt;
 
         let _ = usage.flags();
+        let _ = usage.reserved();
+    }
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn smoke_usage_watch() -> Result<(), Box<dyn Error>> {
+    let watch = disk::usage_watch("/", std::time::Duration::from_millis(10));
+    futures::pin_mut!(watch);
+    let snapshots = watch.take(2).collect::<Vec<_>>().await;
+
+    assert_eq!(snapshots.len(), 2);
+    for usage in snapshots {
+        let _ = usage?.ratio();
     }
 
     Ok(())
@@ -102,11 +155,53 @@ async fn smoke_io_counters() -> Result<(), Box<dyn Error>> {
         let _ = count.write_count();
         let _ = count.read_bytes();
         let _ = count.write_bytes();
+
+        #[cfg(target_os = "linux")]
+        {
+            use heim_disk::os::linux::IoCountersExt;
+
+            let _ = count.read_merged_count();
+            let _ = count.write_merged_count();
+            let _ = count.discard_count();
+            let _ = count.discard_merged_count();
+            let _ = count.discard_bytes();
+        }
+
+        let rates = count.rate_since(&count, Time::new::<time::second>(1.0));
+        assert_eq!(rates.read_count(), 0.0);
+        assert_eq!(rates.write_count(), 0.0);
+
+        let delta = count.delta(&count, Time::new::<time::second>(1.0));
+        assert_eq!(delta.read_count(), 0);
+        assert_eq!(delta.write_count(), 0);
+        assert_eq!(delta.rates().read_count(), 0.0);
     }
 
     Ok(())
 }
 
+#[heim_derive::test]
+async fn smoke_io_counters_for() -> Result<(), Box<dyn Error>> {
+    let counters = disk::io_counters().await?;
+    futures::pin_mut!(counters);
+    if let Some(count) = counters.next().await {
+        let count = count?;
+        let name = count.device_name().to_string_lossy();
+
+        let found = disk::io_counters_for(&name).await?;
+        assert_eq!(
+            found.map(|c| c.device_name().to_os_string()),
+            Some(count.device_name().to_os_string())
+        );
+    }
+
+    assert!(disk::io_counters_for("heim-missing-device")
+        .await?
+        .is_none());
+
+    Ok(())
+}
+
 #[heim_derive::test]
 async fn smoke_io_counters_physical() -> Result<(), Box<dyn Error>> {
     #[cfg(target_os = "windows")]
@@ -130,3 +225,56 @@ async fn smoke_io_counters_physical() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_device_usage() -> Result<(), Box<dyn Error>> {
+    // Non device-mapper devices are ignored
+    let usage = disk::os::linux::device_usage("heim-missing-device").await?;
+    assert!(usage.is_none());
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn smoke_io_counters_map() -> Result<(), Box<dyn Error>> {
+    let counters = disk::io_counters_map().await?;
+
+    for (name, count) in counters.iter() {
+        assert_eq!(name, count.device_name());
+    }
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn smoke_io_counters_watch() -> Result<(), Box<dyn Error>> {
+    let watch = disk::io_counters_watch(std::time::Duration::from_millis(10));
+    futures::pin_mut!(watch);
+    let snapshots = watch.take(2).collect::<Vec<_>>().await;
+
+    assert_eq!(snapshots.len(), 2);
+    for snapshot in snapshots {
+        let _ = snapshot?;
+    }
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn smoke_block_devices() -> Result<(), Box<dyn Error>> {
+    let devices = disk::block_devices().await?;
+    futures::pin_mut!(devices);
+    while let Some(device) = devices.next().await {
+        let device = device?;
+
+        let _ = device.name();
+        let _ = device.size();
+        let _ = device.model();
+        let _ = device.is_rotational();
+        let _ = device.is_removable();
+        let _ = device.parent();
+    }
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+//! Synchronous versions of the host information functions.
+//!
+//! Each function drives the corresponding async function to completion
+//! on the current thread, so there is no need to set up any async runtime.
+//!
+//! ```rust
+//! let uptime = heim_host::blocking::uptime()?;
+//! # Ok::<(), heim_common::Error>(())
+//! ```
+
+use heim_common::prelude::*;
+use heim_runtime as rt;
+
+use crate::{Platform, Time, User};
+
+/// Synchronous version of the [boot_time](../fn.boot_time.html) function.
+pub fn boot_time() -> Result<Time> {
+    rt::block_on(crate::boot_time())
+}
+
+/// Synchronous version of the [hostname](../fn.hostname.html) function.
+pub fn hostname() -> Result<String> {
+    rt::block_on(crate::hostname())
+}
+
+/// Synchronous version of the [page_size](../fn.page_size.html) function.
+pub fn page_size() -> Result<u64> {
+    rt::block_on(crate::page_size())
+}
+
+/// Synchronous version of the [platform](../fn.platform.html) function.
+pub fn platform() -> Result<Platform> {
+    rt::block_on(crate::platform())
+}
+
+/// Synchronous version of the [process_count](../fn.process_count.html) function.
+pub fn process_count() -> Result<u64> {
+    rt::block_on(crate::process_count())
+}
+
+/// Synchronous version of the [thread_count](../fn.thread_count.html) function.
+pub fn thread_count() -> Result<u64> {
+    rt::block_on(crate::thread_count())
+}
+
+/// Synchronous version of the [uptime](../fn.uptime.html) function.
+pub fn uptime() -> Result<Time> {
+    rt::block_on(crate::uptime())
+}
+
+/// Synchronous version of the [users](../fn.users.html) function.
+pub fn users() -> Result<Vec<User>> {
+    rt::block_on(async { crate::users().await?.try_collect().await })
+}
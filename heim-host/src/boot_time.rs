@@ -4,6 +4,10 @@ use crate::{sys, Time};
 
 /// Returns system boot [Time] since the UNIX epoch.
 ///
+/// Value is read directly from the OS (`btime` from `/proc/stat` for Linux,
+/// `kern.boottime` sysctl for macOS) instead of being derived from the [uptime].
+///
+/// [uptime]: ./fn.uptime.html
 /// [Time]: ./struct.Time.html
 pub async fn boot_time() -> Result<Time> {
     sys::boot_time().await
@@ -0,0 +1,17 @@
+use heim_common::prelude::*;
+
+use crate::sys;
+
+/// Returns `true` if the current process is running with the elevated privileges.
+///
+/// It can be used to decide whether it is worth to attempt the privileged operations
+/// (ex. reading other users processes data) or to degrade gracefully instead.
+///
+/// ## Compatibility
+///
+/// * For *nix systems it checks if the effective user ID is `0` (`root`)
+/// * For Windows it checks if the process token is a member of the Administrators group,
+///   which is `true` only for the elevated processes when UAC is enabled
+pub async fn is_elevated() -> Result<bool> {
+    sys::is_elevated().await
+}
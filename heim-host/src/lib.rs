@@ -32,16 +32,36 @@ pub use platforms::target::Arch;
 
 mod sys;
 
+#[cfg(feature = "blocking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub mod blocking;
+
 mod boot_time;
+mod elevated;
 pub mod os;
+mod page_size;
 mod platform;
+mod processes;
 mod uptime;
 mod users;
 
 pub use self::boot_time::*;
+pub use self::elevated::*;
+pub use self::page_size::*;
 pub use self::platform::*;
+pub use self::processes::*;
 pub use self::uptime::*;
 pub use self::users::*;
 
 pub use heim_common::units::Time;
 pub use heim_common::Pid;
+
+/// Re-exports of the commonly used host types and the measurement units for them.
+///
+/// ```rust
+/// use heim_host::prelude::*;
+/// ```
+pub mod prelude {
+    pub use crate::{Arch, Pid, Platform, User};
+    pub use heim_common::units::{time, Time};
+}
@@ -2,7 +2,11 @@
 
 use std::net::IpAddr;
 
-use crate::Pid;
+#[cfg(target_os = "linux")]
+use heim_common::prelude::*;
+use heim_common::units::Information;
+
+use crate::{Pid, Time};
 
 cfg_if::cfg_if! {
     // aarch64-unknown-linux-gnu has different type
@@ -28,6 +32,10 @@ cfg_if::cfg_if! {
 /// In Linux user information is provided by `utmpx` (see `man utmpx(5)`),
 /// trait methods are representing fields of this struct.
 ///
+/// Only the `USER_PROCESS` records are yielded by the [users] stream,
+/// dead processes, boot time and other bookkeeping records are skipped.
+///
+/// [users]: ../../fn.users.html
 /// [User]: ../../struct.User.html
 pub trait UserExt {
     /// Returns the `Pid` of login process.
@@ -40,6 +48,9 @@ pub trait UserExt {
     fn id(&self) -> &str;
 
     /// Returns the hostname for remote login.
+    ///
+    /// Empty string is returned for the local logins,
+    /// while remote sessions (ex. via SSH) have it set to the remote host name or address.
     fn hostname(&self) -> &str;
 
     /// Returns the IP address of remote host.
@@ -52,6 +63,11 @@ pub trait UserExt {
     /// Note that session id type is not portable
     /// and varies depending on target architecture.
     fn session_id(&self) -> SessionId;
+
+    /// Returns the session login time as a [Time] since the UNIX epoch.
+    ///
+    /// [Time]: ../../struct.Time.html
+    fn login_time(&self) -> Time;
 }
 
 #[cfg(target_os = "linux")]
@@ -79,4 +95,84 @@ impl UserExt for crate::User {
     fn session_id(&self) -> SessionId {
         self.as_ref().session_id()
     }
+
+    fn login_time(&self) -> Time {
+        self.as_ref().login_time()
+    }
+}
+
+/// Returns amount of the allocated file descriptors and their system-wide maximum.
+///
+/// Allocated amount growing up to the maximum is a usual symptom of the file descriptors leak.
+///
+/// Values are fetched from the `/proc/sys/fs/file-nr` file.
+#[cfg(target_os = "linux")]
+pub async fn open_fds() -> Result<(u64, u64)> {
+    crate::sys::open_fds().await
+}
+
+/// Kernel module state.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum KernelModuleState {
+    /// Module is loaded and running.
+    Live,
+    /// Module is being loaded.
+    Loading,
+    /// Module is being unloaded.
+    Unloading,
+}
+
+/// Loaded kernel module.
+///
+/// See [kernel_modules] function.
+///
+/// [kernel_modules]: ./fn.kernel_modules.html
+#[derive(Debug, Clone)]
+pub struct KernelModule {
+    pub(crate) name: String,
+    pub(crate) size: Information,
+    pub(crate) use_count: Option<u64>,
+    pub(crate) dependencies: Vec<String>,
+    pub(crate) state: KernelModuleState,
+}
+
+impl KernelModule {
+    /// Returns module name, ex. `nvme`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns memory size used by the module.
+    pub fn size(&self) -> Information {
+        self.size
+    }
+
+    /// Returns amount of the module users (other modules or processes).
+    ///
+    /// `None` is returned if kernel was built without modules unloading support.
+    pub fn use_count(&self) -> Option<u64> {
+        self.use_count
+    }
+
+    /// Returns names of modules which are depending on this module.
+    pub fn dependencies(&self) -> &[String] {
+        &self.dependencies
+    }
+
+    /// Returns module state.
+    pub fn state(&self) -> KernelModuleState {
+        self.state
+    }
+}
+
+/// Returns a stream over the loaded [kernel modules].
+///
+/// Modules are parsed from the `/proc/modules` file.
+/// Empty stream is returned if kernel was built without loadable modules support.
+///
+/// [kernel modules]: ./struct.KernelModule.html
+#[cfg(target_os = "linux")]
+pub async fn kernel_modules() -> Result<impl Stream<Item = Result<KernelModule>>> {
+    crate::sys::kernel_modules().await
 }
@@ -1,5 +1,8 @@
 //! macOS-specific extensions.
 
+#[cfg(target_os = "macos")]
+use heim_common::prelude::*;
+
 use crate::Pid;
 
 /// macOS-specific extensions for [User].
@@ -40,3 +43,54 @@ impl UserExt for crate::User {
         self.as_ref().hostname()
     }
 }
+
+/// Mac hardware model.
+///
+/// See [model_identifier] function.
+///
+/// [model_identifier]: ./fn.model_identifier.html
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Model {
+    identifier: String,
+    marketing_name: Option<String>,
+}
+
+impl Model {
+    /// Returns model identifier, ex. `MacBookPro18,3`.
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_str()
+    }
+
+    /// Returns model marketing name, ex. `MacBook Pro (14-inch, 2021)`, if available.
+    ///
+    /// At the moment marketing name can be resolved for Apple Silicon machines only.
+    pub fn marketing_name(&self) -> Option<&str> {
+        self.marketing_name.as_deref()
+    }
+}
+
+/// Returns hardware [Model] of this Mac.
+///
+/// Model identifier is fetched via the `hw.model` sysctl,
+/// while marketing name is resolved from the IOKit device tree, if possible.
+///
+/// [Model]: ./struct.Model.html
+#[cfg(target_os = "macos")]
+pub async fn model_identifier() -> Result<Model> {
+    let (identifier, marketing_name) = crate::sys::model_identifier().await?;
+
+    Ok(Model {
+        identifier,
+        marketing_name,
+    })
+}
+
+/// Returns amount of the allocated file descriptors and their system-wide maximum.
+///
+/// Allocated amount growing up to the maximum is a usual symptom of the file descriptors leak.
+///
+/// Values are fetched via the `kern.num_files` and `kern.maxfiles` sysctls.
+#[cfg(target_os = "macos")]
+pub async fn open_fds() -> Result<(u64, u64)> {
+    crate::sys::open_fds().await
+}
@@ -2,6 +2,11 @@
 
 use std::net::IpAddr;
 
+#[cfg(target_os = "windows")]
+use heim_common::prelude::*;
+#[cfg(target_os = "windows")]
+use heim_common::units::Time;
+
 /// Extension for [User] struct.
 ///
 /// [User]: ../../struct.User.html
@@ -49,3 +54,23 @@ impl PlatformExt for crate::Platform {
         self.as_ref().domain()
     }
 }
+
+/// Returns the Windows product ID, ex. `00330-80000-00000-AA123`.
+///
+/// Value is fetched from the `ProductId` value
+/// of the `HKLM\SOFTWARE\Microsoft\Windows NT\CurrentVersion` registry key.
+#[cfg(target_os = "windows")]
+pub async fn product_id() -> Result<String> {
+    crate::sys::product_id().await
+}
+
+/// Returns the Windows installation date as a time passed since the UNIX epoch.
+///
+/// Value is fetched from the `InstallDate` value
+/// of the `HKLM\SOFTWARE\Microsoft\Windows NT\CurrentVersion` registry key.
+///
+/// Note that major Windows updates are re-writing this value.
+#[cfg(target_os = "windows")]
+pub async fn install_date() -> Result<Time> {
+    crate::sys::install_date().await
+}
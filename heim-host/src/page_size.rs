@@ -0,0 +1,17 @@
+use heim_common::prelude::*;
+
+use crate::sys;
+
+/// Returns memory page size in bytes.
+///
+/// It can be used to convert the page counts (ex. process RSS from the `/proc/{pid}/statm`)
+/// into bytes.
+///
+/// ## Compatibility
+///
+/// * For Linux and macOS `sysconf(_SC_PAGESIZE)` is used; value is fetched once
+///   and cached for the subsequent calls, as it can't change at runtime
+/// * For Windows `dwPageSize` field of the `GetSystemInfo` function result is used
+pub async fn page_size() -> Result<u64> {
+    sys::page_size().await
+}
@@ -78,9 +78,48 @@ impl fmt::Debug for Platform {
     }
 }
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde_crate::Serialize for Platform {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde_crate::Serializer,
+    {
+        use serde_crate::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Platform", 5)?;
+        state.serialize_field("system", self.system())?;
+        state.serialize_field("release", self.release())?;
+        state.serialize_field("version", self.version())?;
+        state.serialize_field("hostname", self.hostname())?;
+        state.serialize_field("architecture", self.architecture().as_str())?;
+        state.end()
+    }
+}
+
 /// Returns [Platform] information.
 ///
 /// [Platform]: ./struct.Platform.html
 pub async fn platform() -> Result<Platform> {
     sys::platform().await.map(Into::into)
 }
+
+/// Returns system hostname.
+///
+/// Same value as the [`Platform::hostname`] returns, but without fetching
+/// the rest of the platform information, which makes it cheaper to call repeatedly.
+///
+/// ## Compatibility
+///
+/// * For Unix systems `gethostname(2)` function is used
+/// * For Windows `GetComputerNameExW` function is used, DNS host name is returned
+///
+/// ## Returns
+///
+/// Error with `InvalidData` kind is returned if hostname is not a valid UTF-8 string
+/// (or UTF-16 for Windows), instead of the lossy conversion.
+///
+/// [`Platform::hostname`]: ./struct.Platform.html#method.hostname
+pub async fn hostname() -> Result<String> {
+    sys::hostname().await
+}
@@ -0,0 +1,32 @@
+use heim_common::prelude::*;
+
+use crate::sys;
+
+/// Returns amount of the processes existing in the system.
+///
+/// Contrary to the enumerating all processes with the `heim-process` crate,
+/// no per-process information is fetched.
+///
+/// ## Compatibility
+///
+/// * For Linux processes are counted by scanning the `/proc` directory
+/// * For macOS `proc_listallpids` function is used
+/// * For Windows `GetPerformanceInfo` function is used
+pub async fn process_count() -> Result<u64> {
+    sys::process_count().await
+}
+
+/// Returns amount of the threads existing in the system, summed for all processes.
+///
+/// ## Compatibility
+///
+/// * For Linux threads amount is read from the `/proc/{pid}/stat` file of each process;
+///   processes which had exited during the scan are skipped
+/// * For macOS this function returns an error, as it is not implemented yet,
+///   [`Error::is_not_supported`] returns `true` for it
+/// * For Windows `GetPerformanceInfo` function is used
+///
+/// [`Error::is_not_supported`]: ../heim_common/struct.Error.html#method.is_not_supported
+pub async fn thread_count() -> Result<u64> {
+    sys::thread_count().await
+}
@@ -4,29 +4,52 @@ use heim_common::{
 };
 use heim_runtime as rt;
 
+// Boot time is stored at the `btime` line of the `/proc/stat` file
+// as an amount of seconds since the UNIX epoch, ex. `btime 1604214432`
+fn parse_btime(contents: &str) -> Option<Result<Time>> {
+    let line = contents.lines().find(|line| line.starts_with("btime "))?;
+    let value = line["btime ".len()..]
+        .trim()
+        .parse::<f64>()
+        .map(Time::new::<time::second>)
+        .map_err(Into::into);
+
+    Some(value)
+}
+
 pub async fn boot_time() -> Result<Time> {
-    let contents = rt::fs::read_to_string(rt::linux::procfs_root().join("stat")).await?;
-
-    for line in contents.lines() {
-        if line.starts_with("btime ") {
-            let mut parts = line.splitn(2, ' ');
-            let _ = parts.next();
-
-            return match parts.next() {
-                Some(raw_value) => raw_value
-                    .parse::<f64>()
-                    .map(Time::new::<time::second>)
-                    .map_err(Into::into),
-                None => Err(Error::missing_key(
-                    "btime",
-                    format!("{}/stat", rt::linux::procfs_root().display()),
-                )),
-            };
-        }
+    rt::linux::ensure_procfs().await?;
+
+    let path = rt::linux::procfs_root().join("stat");
+    let contents = rt::fs::read_to_string(&path)
+        .await
+        .map_err(|e| Error::from(e).with_file(&path))?;
+
+    match parse_btime(&contents) {
+        Some(value) => value,
+        None => Err(Error::missing_key("btime", format!("{}", path.display()))),
     }
+}
 
-    Err(Error::missing_key(
-        "btime",
-        format!("{}/stat", rt::linux::procfs_root().display()),
-    ))
+#[cfg(test)]
+mod tests {
+    use heim_common::units::time;
+
+    use super::parse_btime;
+
+    #[test]
+    fn test_parse_btime() {
+        const STAT: &str = "cpu  4705 356 584 3699 23 23 0 0 0 0
+intr 114930548 113199788 3 0 5 263 0 4
+ctxt 1990473
+btime 1604214432
+processes 2915
+";
+
+        let value = parse_btime(STAT).unwrap().unwrap();
+        assert_eq!(value.get::<time::second>(), 1_604_214_432.0);
+
+        assert!(parse_btime("btime  \n").unwrap().is_err());
+        assert!(parse_btime("ctxt 1990473\n").is_none());
+    }
 }
@@ -0,0 +1,110 @@
+use std::io;
+use std::str::FromStr;
+
+use heim_common::prelude::*;
+use heim_common::units::{information, Information};
+use heim_common::utils::iter::*;
+use heim_runtime as rt;
+
+use crate::os::linux::{KernelModule, KernelModuleState};
+
+impl FromStr for KernelModuleState {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<KernelModuleState> {
+        match s {
+            "Live" => Ok(KernelModuleState::Live),
+            "Loading" => Ok(KernelModuleState::Loading),
+            "Unloading" => Ok(KernelModuleState::Unloading),
+            other => {
+                let inner = io::Error::from(io::ErrorKind::InvalidData);
+                Err(Error::from(inner).with_message(format!("Unknown module state: {}", other)))
+            }
+        }
+    }
+}
+
+impl FromStr for KernelModule {
+    type Err = Error;
+
+    // Format is `<name> <size> <use count> <dependencies> <state> <address>`,
+    // ex. `snd_hda_codec 172032 2 snd_hda_codec_generic,snd_hda_intel, Live 0x0000000000000000`
+    fn from_str(s: &str) -> Result<KernelModule> {
+        let mut parts = s.split_whitespace();
+        let name: String = parts.try_from_next()?;
+        let size = parts
+            .try_parse_next()
+            .map(|bytes: u64| Information::new::<information::byte>(bytes))?;
+        // Use count is `-` if kernel was built without `CONFIG_MODULE_UNLOAD`
+        let use_count = match parts.try_next()? {
+            "-" => None,
+            value => Some(value.parse()?),
+        };
+        // Dependencies list has a trailing comma, or it is `-` if there are no dependencies
+        let dependencies = match parts.try_next()? {
+            "-" => Vec::new(),
+            value => value
+                .split(',')
+                .filter(|name| !name.is_empty())
+                .map(ToString::to_string)
+                .collect(),
+        };
+        let state = parts.try_parse_next()?;
+
+        Ok(KernelModule {
+            name,
+            size,
+            use_count,
+            dependencies,
+            state,
+        })
+    }
+}
+
+pub async fn kernel_modules() -> Result<impl Stream<Item = Result<KernelModule>>> {
+    rt::linux::ensure_procfs().await?;
+
+    let path = rt::linux::procfs_root().join("modules");
+    match rt::fs::read_lines_into::<_, _, Error>(path.clone()).await {
+        Ok(stream) => Ok(stream.left_stream()),
+        // Kernel was built without loadable modules support
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(stream::empty().right_stream()),
+        Err(e) => Err(Error::from(e).with_file(path)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use heim_common::units::information;
+
+    use super::KernelModule;
+    use crate::os::linux::KernelModuleState;
+
+    #[test]
+    fn test_parse_module() {
+        let line =
+            "snd_hda_codec 172032 2 snd_hda_codec_generic,snd_hda_intel, Live 0x0000000000000000";
+        let module = KernelModule::from_str(line).unwrap();
+
+        assert_eq!(module.name(), "snd_hda_codec");
+        assert_eq!(module.size().get::<information::byte>(), 172_032);
+        assert_eq!(module.use_count(), Some(2));
+        assert_eq!(
+            module.dependencies(),
+            &["snd_hda_codec_generic", "snd_hda_intel"]
+        );
+        assert_eq!(module.state(), KernelModuleState::Live);
+    }
+
+    #[test]
+    fn test_parse_module_without_dependencies() {
+        let line = "nvme_core 135168 - - Loading 0x0000000000000000";
+        let module = KernelModule::from_str(line).unwrap();
+
+        assert_eq!(module.use_count(), None);
+        assert!(module.dependencies().is_empty());
+        assert_eq!(module.state(), KernelModuleState::Loading);
+    }
+}
@@ -1,7 +1,13 @@
 mod boot_time;
+mod kernel_modules;
+mod open_fds;
+mod processes;
 mod uptime;
 mod users;
 
 pub use self::boot_time::*;
+pub use self::kernel_modules::*;
+pub use self::open_fds::*;
+pub use self::processes::*;
 pub use self::uptime::*;
 pub use self::users::*;
@@ -0,0 +1,19 @@
+use heim_common::prelude::*;
+use heim_common::utils::iter::*;
+use heim_runtime as rt;
+
+pub async fn open_fds() -> Result<(u64, u64)> {
+    let path = rt::linux::procfs_root().join("sys/fs/file-nr");
+    let contents = rt::fs::read_to_string(path.clone())
+        .await
+        .map_err(|e| Error::from(e).with_file(&path))?;
+
+    // Format is `<allocated> <allocated but unused> <max>`,
+    // where `<max>` is the same to the `/proc/sys/fs/file-max` value.
+    let mut parts = contents.split_whitespace();
+    let allocated: u64 = parts.try_parse_next()?;
+    let _unused: u64 = parts.try_parse_next()?;
+    let max: u64 = parts.try_parse_next()?;
+
+    Ok((allocated, max))
+}
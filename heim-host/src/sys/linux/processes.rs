@@ -0,0 +1,105 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use heim_common::prelude::*;
+use heim_common::Pid;
+use heim_runtime as rt;
+
+// Process directories are named by their PIDs,
+// other entries (ex. `self` or `sys`) are ignored
+fn pid_dirs() -> Result<impl Iterator<Item = PathBuf>> {
+    let root = rt::linux::procfs_root();
+    let entries = fs::read_dir(root).map_err(|e| Error::from(e).with_file(root))?;
+
+    let iter = entries.filter_map(|entry| {
+        // Entry listing might fail if process had exited while `/proc` was read
+        let entry = entry.ok()?;
+        let is_pid = entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.parse::<Pid>().ok())
+            .is_some();
+
+        if is_pid {
+            Some(entry.path())
+        } else {
+            None
+        }
+    });
+
+    Ok(iter)
+}
+
+// Threads amount is the 20th field of the `/proc/{pid}/stat` file.
+//
+// Process name (2nd field) is wrapped into parentheses and it might contain
+// whitespaces and parentheses too, so fields are counted after the last `)`.
+fn parse_num_threads(stat: &str) -> Result<u64> {
+    let offset = stat
+        .rfind(')')
+        .ok_or_else(|| Error::missing_key("comm", "/proc/{pid}/stat"))?;
+
+    stat[offset + 1..]
+        .split_whitespace()
+        .nth(17)
+        .ok_or_else(|| Error::missing_key("num_threads", "/proc/{pid}/stat"))?
+        .parse()
+        .map_err(Into::into)
+}
+
+// Process might exit between the `/proc` listing and reading its files
+fn is_vanished(e: &io::Error) -> bool {
+    e.kind() == io::ErrorKind::NotFound || e.raw_os_error() == Some(libc::ESRCH)
+}
+
+fn count_threads() -> Result<u64> {
+    let mut total = 0;
+    for path in pid_dirs()? {
+        let path = path.join("stat");
+        let stat = match fs::read_to_string(&path) {
+            Ok(stat) => stat,
+            Err(e) if is_vanished(&e) => continue,
+            Err(e) => return Err(Error::from(e).with_file(path)),
+        };
+
+        total += parse_num_threads(&stat)?;
+    }
+
+    Ok(total)
+}
+
+pub async fn process_count() -> Result<u64> {
+    rt::linux::ensure_procfs().await?;
+
+    rt::spawn_blocking(|| pid_dirs().map(|dirs| dirs.count() as u64)).await
+}
+
+pub async fn thread_count() -> Result<u64> {
+    rt::linux::ensure_procfs().await?;
+
+    rt::spawn_blocking(count_threads).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_num_threads;
+
+    #[test]
+    fn test_parse_num_threads() {
+        let stat = "1 (systemd) S 0 1 1 0 -1 4194560 53887 2356355 96 1127 148 341 4294 2037 \
+            20 0 1 0 23 172560384 3178 18446744073709551615 1 1 0 0 0 0 671173123 4096 1260 \
+            0 0 0 17 2 0 0 0 0 0 0 0 0 0 0 0 0 0";
+        assert_eq!(parse_num_threads(stat).unwrap(), 1);
+
+        let stat = "4242 (Web Content (1)) S 1 4242 4242 0 -1 4194560 5 0 0 0 10 5 0 0 \
+            20 0 27 0 100 100 100";
+        assert_eq!(parse_num_threads(stat).unwrap(), 27);
+    }
+
+    #[test]
+    fn test_parse_malformed_num_threads() {
+        assert!(parse_num_threads("1 systemd S 0").is_err());
+        assert!(parse_num_threads("1 (systemd) S 0 1 1").is_err());
+    }
+}
@@ -4,7 +4,11 @@ use heim_common::{
 };
 use heim_runtime as rt;
 
+use crate::sys::clock;
+
 pub async fn uptime() -> Result<Time> {
+    rt::linux::ensure_procfs().await?;
+
     let contents = rt::fs::read_to_string(rt::linux::procfs_root().join("uptime")).await?;
 
     match contents.splitn(2, ' ').next() {
@@ -19,3 +23,8 @@ pub async fn uptime() -> Result<Time> {
         )),
     }
 }
+
+pub async fn uptime_monotonic() -> Result<Time> {
+    // Unlike `CLOCK_MONOTONIC`, it includes time spent in suspend
+    clock::clock_gettime(libc::CLOCK_BOOTTIME)
+}
@@ -1,40 +1,49 @@
 use std::net::IpAddr;
 
 use heim_common::prelude::*;
+use heim_common::units::Time;
 use heim_common::Pid;
 
 use crate::os::linux::SessionId;
 
+/// `utmpx` functions are no-op stubs in musl, so there are no user sessions to return,
+/// see https://github.com/heim-rs/heim/issues/141
+///
+/// Type is uninhabited so that the accessors below can't be called at all.
 #[derive(Debug)]
-pub struct User;
+pub enum User {}
 
 impl User {
     pub fn username(&self) -> &str {
-        unimplemented!("https://github.com/heim-rs/heim/issues/141")
+        match *self {}
     }
 
     pub fn terminal(&self) -> &str {
-        unimplemented!("https://github.com/heim-rs/heim/issues/141")
+        match *self {}
     }
 
     pub fn id(&self) -> &str {
-        unimplemented!("https://github.com/heim-rs/heim/issues/141")
+        match *self {}
     }
 
     pub fn pid(&self) -> Pid {
-        unimplemented!("https://github.com/heim-rs/heim/issues/141")
+        match *self {}
     }
 
     pub fn hostname(&self) -> &str {
-        unimplemented!("https://github.com/heim-rs/heim/issues/141")
+        match *self {}
     }
 
     pub fn address(&self) -> Option<IpAddr> {
-        unimplemented!("https://github.com/heim-rs/heim/issues/141")
+        match *self {}
     }
 
     pub fn session_id(&self) -> SessionId {
-        unimplemented!("https://github.com/heim-rs/heim/issues/141")
+        match *self {}
+    }
+
+    pub fn login_time(&self) -> Time {
+        match *self {}
     }
 }
 
@@ -2,6 +2,7 @@ use std::ffi::CStr;
 use std::net::IpAddr;
 
 use heim_common::prelude::*;
+use heim_common::units::{time, Time};
 use heim_common::Pid;
 
 use crate::os::linux::SessionId;
@@ -16,6 +17,7 @@ pub struct User {
     pid: libc::pid_t,
     addr: Option<IpAddr>,
     session_id: SessionId,
+    login_time: Time,
 }
 
 impl User {
@@ -46,6 +48,10 @@ impl User {
     pub fn session_id(&self) -> SessionId {
         self.session_id
     }
+
+    pub fn login_time(&self) -> Time {
+        self.login_time
+    }
 }
 
 impl From<libc::utmpx> for User {
@@ -79,6 +85,9 @@ impl From<libc::utmpx> for User {
             pid: entry.ut_pid,
             session_id: entry.ut_session,
             addr: from_ut_addr_v6(&entry.ut_addr_v6),
+            login_time: Time::new::<time::second>(
+                entry.ut_tv.tv_sec as f64 + entry.ut_tv.tv_usec as f64 / 1_000_000.0,
+            ),
         }
     }
 }
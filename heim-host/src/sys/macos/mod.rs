@@ -4,10 +4,16 @@ use heim_common::{Error, Result};
 use mach::{kern_return, mach_time};
 
 mod boot_time;
+mod model;
+mod open_fds;
+mod processes;
 mod uptime;
 mod users;
 
 pub use self::boot_time::*;
+pub use self::model::*;
+pub use self::open_fds::*;
+pub use self::processes::*;
 pub use self::uptime::*;
 pub use self::users::*;
 
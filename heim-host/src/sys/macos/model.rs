@@ -0,0 +1,28 @@
+use heim_common::prelude::*;
+use heim_common::sys::macos::iokit::{DictionaryProps, IoMasterPort};
+use heim_common::sys::macos::sysctl;
+
+// Apple Silicon machines are providing marketing name in the device tree;
+// for Intel-based machines it is missing and `None` is returned.
+fn marketing_name() -> Result<Option<String>> {
+    let port = IoMasterPort::new()?;
+    let product = port.get_entry_from_path(b"IODeviceTree:/product\0")?;
+    let mut name = product.properties()?.get_data("product-name")?;
+
+    while name.last() == Some(&0) {
+        let _ = name.pop();
+    }
+
+    if name.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(String::from_utf8_lossy(&name).into_owned()))
+    }
+}
+
+pub async fn model_identifier() -> Result<(String, Option<String>)> {
+    let identifier = unsafe { sysctl::sysctlbyname_string(b"hw.model\0")? };
+    let name = marketing_name().unwrap_or(None);
+
+    Ok((identifier, name))
+}
@@ -0,0 +1,9 @@
+use heim_common::prelude::*;
+use heim_common::sys::macos::sysctl;
+
+pub async fn open_fds() -> Result<(u64, u64)> {
+    let allocated = unsafe { sysctl::sysctlbyname::<libc::c_int>(b"kern.num_files\0")? };
+    let max = unsafe { sysctl::sysctlbyname::<libc::c_int>(b"kern.maxfiles\0")? };
+
+    Ok((allocated as u64, max as u64))
+}
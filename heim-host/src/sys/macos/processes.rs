@@ -0,0 +1,40 @@
+use std::mem;
+use std::ptr;
+
+use heim_common::not_supported;
+use heim_common::prelude::*;
+
+pub async fn process_count() -> Result<u64> {
+    loop {
+        // First call returns the PIDs amount estimation with some extra space reserved,
+        // in case if new processes are spawned before the second call
+        let capacity = unsafe { libc::proc_listallpids(ptr::null_mut(), 0) };
+        if capacity <= 0 {
+            return Err(Error::last_os_error().with_ffi("proc_listallpids"));
+        }
+
+        let mut pids: Vec<libc::pid_t> = vec![0; capacity as usize];
+        let count = unsafe {
+            libc::proc_listallpids(
+                pids.as_mut_ptr() as *mut libc::c_void,
+                (pids.len() * mem::size_of::<libc::pid_t>()) as libc::c_int,
+            )
+        };
+        if count <= 0 {
+            return Err(Error::last_os_error().with_ffi("proc_listallpids"));
+        }
+
+        // Buffer is filled completely, so it might be not enough for all of the processes
+        if count as usize >= pids.len() {
+            continue;
+        }
+
+        return Ok(count as u64);
+    }
+}
+
+pub async fn thread_count() -> Result<u64> {
+    // TODO: Threads amount can be fetched via `proc_pidinfo(PROC_PIDTASKINFO)`
+    // for each process, but it requires elevated privileges for other users' processes
+    Err(not_supported("thread count"))
+}
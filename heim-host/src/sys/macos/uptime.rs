@@ -4,6 +4,7 @@ use heim_common::units::{time, Time};
 use mach::mach_time;
 
 use super::TIME_BASE;
+use crate::sys::clock;
 
 pub async fn uptime() -> Result<Time> {
     // TODO: Possible value truncation
@@ -13,3 +14,7 @@ pub async fn uptime() -> Result<Time> {
 
     Ok(Time::new::<time::nanosecond>(nano_secs))
 }
+
+pub async fn uptime_monotonic() -> Result<Time> {
+    clock::clock_gettime(libc::CLOCK_UPTIME_RAW)
+}
@@ -0,0 +1,19 @@
+use std::mem;
+
+use heim_common::prelude::*;
+use heim_common::units::{time, Time};
+
+/// Returns the `clock` clock value.
+pub fn clock_gettime(clock: libc::clockid_t) -> Result<Time> {
+    let mut value = mem::MaybeUninit::<libc::timespec>::uninit();
+    let result = unsafe { libc::clock_gettime(clock, value.as_mut_ptr()) };
+
+    if result == 0 {
+        let value = unsafe { value.assume_init() };
+
+        Ok(Time::new::<time::second>(value.tv_sec as f64)
+            + Time::new::<time::nanosecond>(value.tv_nsec as f64))
+    } else {
+        Err(Error::last_os_error().with_ffi("clock_gettime"))
+    }
+}
@@ -0,0 +1,8 @@
+use heim_common::prelude::*;
+
+pub async fn is_elevated() -> Result<bool> {
+    // `geteuid` is always successful
+    let euid = unsafe { libc::geteuid() };
+
+    Ok(euid == 0)
+}
@@ -1,7 +1,12 @@
+pub mod clock;
+mod elevated;
+mod page_size;
 mod platform;
 #[cfg(not(target_env = "musl"))]
 mod users;
 
+pub use self::elevated::*;
+pub use self::page_size::*;
 pub use self::platform::*;
 #[cfg(not(target_env = "musl"))]
 pub use self::users::*;
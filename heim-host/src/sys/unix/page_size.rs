@@ -0,0 +1,6 @@
+use heim_common::prelude::*;
+use heim_common::sys::unix;
+
+pub async fn page_size() -> Result<u64> {
+    unix::page_size()
+}
@@ -1,4 +1,5 @@
 use std::ffi::CStr;
+use std::io;
 use std::mem;
 use std::str::FromStr;
 
@@ -72,6 +73,53 @@ pub async fn platform() -> Result<Platform> {
     }
 }
 
+// `HOST_NAME_MAX` is 64 bytes for Linux and 255 bytes for macOS,
+// so the initial buffer is enough for most of the cases,
+// but it is grown if the hostname does not fit into it.
+const HOSTNAME_BUFFER_SIZE: usize = 256;
+const HOSTNAME_BUFFER_MAX_SIZE: usize = 64 * 1024;
+
+// Returns hostname bytes up to the first NUL byte, or `None` if it might be truncated.
+//
+// POSIX does not specify if the truncated hostname is NUL-terminated,
+// so the name which fills the whole buffer is considered as truncated too.
+fn hostname_from_buffer(mut buffer: Vec<u8>) -> Option<Vec<u8>> {
+    match buffer.iter().position(|byte| *byte == 0) {
+        Some(end) if end + 1 < buffer.len() => {
+            buffer.truncate(end);
+            Some(buffer)
+        }
+        _ => None,
+    }
+}
+
+pub async fn hostname() -> Result<String> {
+    let mut size = HOSTNAME_BUFFER_SIZE;
+    loop {
+        let mut buffer = vec![0u8; size];
+        let result = unsafe { libc::gethostname(buffer.as_mut_ptr() as *mut libc::c_char, size) };
+        if result == 0 {
+            if let Some(name) = hostname_from_buffer(buffer) {
+                return String::from_utf8(name).map_err(Into::into);
+            }
+        } else {
+            let e = Error::last_os_error();
+            // glibc fails with `ENAMETOOLONG` and some other implementations with `EINVAL`
+            // if hostname does not fit into the buffer
+            match e.raw_os_error() {
+                Some(libc::ENAMETOOLONG) | Some(libc::EINVAL) => {}
+                _ => return Err(e.with_ffi("gethostname")),
+            }
+        }
+
+        if size >= HOSTNAME_BUFFER_MAX_SIZE {
+            let inner = io::Error::from_raw_os_error(libc::ENAMETOOLONG);
+            return Err(Error::from(inner).with_ffi("gethostname"));
+        }
+        size *= 2;
+    }
+}
+
 /// Arch::from_str only handles the exact names used
 /// by the rust compiler (that's the policy of the platforms crate)
 /// However, uname -m has more varied output, which we need to detect
@@ -86,3 +134,28 @@ fn arch_from_uname(raw: &str) -> Option<Arch> {
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::hostname_from_buffer;
+
+    #[test]
+    fn test_hostname_from_buffer() {
+        assert_eq!(
+            hostname_from_buffer(b"tardis\0\0\0".to_vec()),
+            Some(b"tardis".to_vec())
+        );
+        // Garbage after the terminating NUL byte is ignored
+        assert_eq!(
+            hostname_from_buffer(b"tardis\0old-name\0".to_vec()),
+            Some(b"tardis".to_vec())
+        );
+        assert_eq!(hostname_from_buffer(b"\0\0".to_vec()), Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_hostname_from_truncated_buffer() {
+        assert_eq!(hostname_from_buffer(b"tardis".to_vec()), None);
+        assert_eq!(hostname_from_buffer(b"tardis\0".to_vec()), None);
+    }
+}
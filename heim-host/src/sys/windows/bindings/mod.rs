@@ -1 +1,2 @@
+pub mod registry;
 pub mod wtsapi32;
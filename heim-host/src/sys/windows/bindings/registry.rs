@@ -0,0 +1,93 @@
+use std::ffi::{OsStr, OsString};
+use std::mem;
+use std::os::windows::ffi::{OsStrExt, OsStringExt};
+use std::ptr;
+
+use winapi::shared::{minwindef, winerror};
+use winapi::um::winreg;
+
+use heim_common::prelude::{Error, Result};
+
+fn to_wide(value: &str) -> Vec<u16> {
+    OsStr::new(value)
+        .encode_wide()
+        .chain(Some(0x0000))
+        .collect()
+}
+
+unsafe fn get_value(
+    subkey: &str,
+    name: &str,
+    flags: minwindef::DWORD,
+    data: minwindef::LPVOID,
+    size: &mut minwindef::DWORD,
+) -> Result<()> {
+    let subkey = to_wide(subkey);
+    let name = to_wide(name);
+
+    // 64-bit registry view is used, in order to get the same values from 32-bit processes too
+    let result = winreg::RegGetValueW(
+        winreg::HKEY_LOCAL_MACHINE,
+        subkey.as_ptr(),
+        name.as_ptr(),
+        flags | winreg::RRF_SUBKEY_WOW6464KEY,
+        ptr::null_mut(),
+        data,
+        size,
+    );
+
+    if result == winerror::ERROR_SUCCESS as i32 {
+        Ok(())
+    } else {
+        Err(Error::from_raw_os_error(result).with_ffi("RegGetValueW"))
+    }
+}
+
+/// Fetches `REG_SZ` value from the `HKEY_LOCAL_MACHINE\{subkey}` key.
+pub fn get_string(subkey: &str, name: &str) -> Result<String> {
+    let mut size: minwindef::DWORD = 0;
+    unsafe {
+        get_value(
+            subkey,
+            name,
+            winreg::RRF_RT_REG_SZ,
+            ptr::null_mut(),
+            &mut size,
+        )?;
+    }
+
+    let mut buffer: Vec<u16> = vec![0; size as usize / mem::size_of::<u16>()];
+    unsafe {
+        get_value(
+            subkey,
+            name,
+            winreg::RRF_RT_REG_SZ,
+            buffer.as_mut_ptr() as minwindef::LPVOID,
+            &mut size,
+        )?;
+    }
+
+    // `size` includes the terminating NUL character
+    let length = buffer.iter().position(|c| *c == 0).unwrap_or(buffer.len());
+    buffer.truncate(length);
+
+    Ok(OsString::from_wide(&buffer).to_string_lossy().into_owned())
+}
+
+/// Fetches `REG_DWORD` value from the `HKEY_LOCAL_MACHINE\{subkey}` key.
+#[allow(trivial_casts)]
+pub fn get_dword(subkey: &str, name: &str) -> Result<minwindef::DWORD> {
+    let mut value: minwindef::DWORD = 0;
+    let mut size = mem::size_of::<minwindef::DWORD>() as minwindef::DWORD;
+    unsafe {
+        get_value(
+            subkey,
+            name,
+            winreg::RRF_RT_REG_DWORD,
+            &mut value as *mut minwindef::DWORD as minwindef::LPVOID,
+            &mut size,
+        )?;
+    }
+
+    Ok(value)
+}
@@ -0,0 +1,53 @@
+use std::ptr;
+
+use winapi::shared::minwindef::{BOOL, FALSE};
+use winapi::um::{securitybaseapi, winnt};
+
+use heim_common::prelude::*;
+
+pub async fn is_elevated() -> Result<bool> {
+    let mut nt_authority = winnt::SID_IDENTIFIER_AUTHORITY {
+        Value: winnt::SECURITY_NT_AUTHORITY,
+    };
+    let mut administrators: winnt::PSID = ptr::null_mut();
+
+    // https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-checktokenmembership
+    let result = unsafe {
+        securitybaseapi::AllocateAndInitializeSid(
+            &mut nt_authority,
+            2,
+            winnt::SECURITY_BUILTIN_DOMAIN_RID,
+            winnt::DOMAIN_ALIAS_RID_ADMINS,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            &mut administrators,
+        )
+    };
+    if result == FALSE {
+        return Err(Error::last_os_error().with_ffi("AllocateAndInitializeSid"));
+    }
+
+    let mut is_member: BOOL = FALSE;
+    // `NULL` token handle means that the impersonation token of the calling thread is used
+    let result = unsafe {
+        securitybaseapi::CheckTokenMembership(ptr::null_mut(), administrators, &mut is_member)
+    };
+    let error = if result == FALSE {
+        Some(Error::last_os_error().with_ffi("CheckTokenMembership"))
+    } else {
+        None
+    };
+
+    unsafe {
+        let _ = securitybaseapi::FreeSid(administrators);
+    }
+
+    match error {
+        Some(e) => Err(e),
+        None => Ok(is_member != FALSE),
+    }
+}
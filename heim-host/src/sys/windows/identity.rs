@@ -0,0 +1,17 @@
+use heim_common::prelude::*;
+use heim_common::units::{time, Time};
+
+use super::bindings::registry;
+
+const CURRENT_VERSION_KEY: &str = r"SOFTWARE\Microsoft\Windows NT\CurrentVersion";
+
+pub async fn product_id() -> Result<String> {
+    registry::get_string(CURRENT_VERSION_KEY, "ProductId")
+}
+
+pub async fn install_date() -> Result<Time> {
+    // Value is stored as a Unix timestamp
+    let timestamp = registry::get_dword(CURRENT_VERSION_KEY, "InstallDate")?;
+
+    Ok(Time::new::<time::second>(f64::from(timestamp)))
+}
@@ -2,11 +2,19 @@ mod bindings;
 mod wrappers;
 
 mod boot_time;
+mod elevated;
+mod identity;
+mod page_size;
 mod platform;
+mod processes;
 mod uptime;
 mod users;
 
 pub use self::boot_time::*;
+pub use self::elevated::*;
+pub use self::identity::*;
+pub use self::page_size::*;
 pub use self::platform::*;
+pub use self::processes::*;
 pub use self::uptime::*;
 pub use self::users::*;
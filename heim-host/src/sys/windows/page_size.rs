@@ -0,0 +1,16 @@
+use std::mem;
+
+use winapi::um::sysinfoapi;
+
+use heim_common::prelude::*;
+
+pub async fn page_size() -> Result<u64> {
+    // `GetSystemInfo` is always successful
+    let info = unsafe {
+        let mut info = mem::MaybeUninit::<sysinfoapi::SYSTEM_INFO>::uninit();
+        sysinfoapi::GetSystemInfo(info.as_mut_ptr());
+        info.assume_init()
+    };
+
+    Ok(u64::from(info.dwPageSize))
+}
@@ -3,7 +3,7 @@ use std::mem;
 
 use ntapi::ntrtl;
 use winapi::shared::ntdef::NULL;
-use winapi::shared::{minwindef, ntstatus};
+use winapi::shared::{minwindef, ntstatus, winerror};
 use winapi::um::sysinfoapi::{
     ComputerNameDnsDomain, ComputerNameDnsHostname, COMPUTER_NAME_FORMAT,
 };
@@ -45,34 +45,19 @@ impl Platform {
             winnt::VER_NT_WORKSTATION => "Windows",
             winnt::VER_NT_SERVER => "Windows Server",
             winnt::VER_NT_DOMAIN_CONTROLLER => "Windows Domain Controller",
-            other => unreachable!("Unknown Windows product type: {}", other),
+            // Should not happen, but there is no need to panic because of it
+            _ => "Windows",
         }
     }
 
     pub fn release(&self) -> &str {
-        // https://docs.microsoft.com/en-us/windows-hardware/drivers/ddi/content/wdm/ns-wdm-_osversioninfoexw#remarks
-
-        let major = self.version.dwMajorVersion;
-        let minor = self.version.dwMinorVersion;
-        let suite_mask = minwindef::DWORD::from(self.version.wSuiteMask);
-        let is_workstation = self.version.wProductType == winnt::VER_NT_WORKSTATION;
-
-        match (major, minor) {
-            (10, 0) => "10",
-            (6, 3) => "8.1",
-            (6, 2) if is_workstation => "8",
-            (6, 2) if !is_workstation => "2012",
-            (6, 1) if is_workstation => "7",
-            (6, 1) if !is_workstation => "2008 R2",
-            (6, 0) if is_workstation => "Vista",
-            (6, 0) if !is_workstation => "2008",
-            (5, 2) if suite_mask == winnt::VER_SUITE_WH_SERVER => "Home Server",
-            (5, 2) if is_workstation => "XP Professional x64 Edition",
-            (5, 2) => "2003",
-            (5, 1) => "XP",
-            (5, 0) => "2000",
-            _ => "unknown",
-        }
+        release_name(
+            self.version.dwMajorVersion,
+            self.version.dwMinorVersion,
+            self.version.dwBuildNumber,
+            self.version.wProductType == winnt::VER_NT_WORKSTATION,
+            minwindef::DWORD::from(self.version.wSuiteMask),
+        )
     }
 
     pub fn version(&self) -> &str {
@@ -116,6 +101,41 @@ impl fmt::Debug for Platform {
     }
 }
 
+// https://docs.microsoft.com/en-us/windows-hardware/drivers/ddi/content/wdm/ns-wdm-_osversioninfoexw#remarks
+//
+// Windows 11 and Windows Server 2016+ are all reporting the `10.0` version,
+// so they can be told apart by the build number only.
+fn release_name(
+    major: minwindef::DWORD,
+    minor: minwindef::DWORD,
+    build: minwindef::DWORD,
+    is_workstation: bool,
+    suite_mask: minwindef::DWORD,
+) -> &'static str {
+    match (major, minor) {
+        (10, 0) if is_workstation && build >= 22000 => "11",
+        (10, 0) if is_workstation => "10",
+        (10, 0) if build >= 26100 => "2025",
+        (10, 0) if build >= 20348 => "2022",
+        (10, 0) if build >= 17763 => "2019",
+        (10, 0) => "2016",
+        (6, 3) if is_workstation => "8.1",
+        (6, 3) => "2012 R2",
+        (6, 2) if is_workstation => "8",
+        (6, 2) => "2012",
+        (6, 1) if is_workstation => "7",
+        (6, 1) => "2008 R2",
+        (6, 0) if is_workstation => "Vista",
+        (6, 0) => "2008",
+        (5, 2) if suite_mask == winnt::VER_SUITE_WH_SERVER => "Home Server",
+        (5, 2) if is_workstation => "XP Professional x64 Edition",
+        (5, 2) => "2003",
+        (5, 1) => "XP",
+        (5, 0) => "2000",
+        _ => "unknown",
+    }
+}
+
 fn get_native_system_info() -> SystemInfo {
     let mut info = mem::MaybeUninit::<sysinfoapi::SYSTEM_INFO>::uninit();
     unsafe {
@@ -145,40 +165,52 @@ fn rtl_get_version() -> winnt::OSVERSIONINFOEXW {
     }
 }
 
-fn get_value_from_get_computer_name_ex_w(kind: COMPUTER_NAME_FORMAT) -> Result<String> {
-    let mut required_size: minwindef::DWORD = 0;
-    let result = unsafe { sysinfoapi::GetComputerNameExW(kind, NULL as _, &mut required_size) };
-    if result != 0 {
-        return Err(Error::last_os_error().with_ffi("GetComputerNameEx"));
-    }
-    // required_size does not contain the trailing null byte
-    let mut size = required_size + 1;
-
-    let mut buffer: Vec<winnt::WCHAR> = vec![0; size as _]; // this ensures that buffer.len = size already (avoiding the need to resort to the unsafe `set_len` later)
-    let result = unsafe { sysinfoapi::GetComputerNameExW(kind, buffer.as_mut_ptr(), &mut size) };
-    if result == 0 {
-        return Err(Error::last_os_error().with_ffi("GetComputerNameEx"));
-    }
+// Computer name might be changed between the calls,
+// so the buffer is re-allocated while it is too small for it.
+fn get_computer_name_ex_w(kind: COMPUTER_NAME_FORMAT) -> Result<Vec<winnt::WCHAR>> {
+    let mut buffer: Vec<winnt::WCHAR> = Vec::new();
+    loop {
+        // On success `size` receives the number of characters copied, not including the
+        // terminating null character; on `ERROR_MORE_DATA` failure it receives the required
+        // buffer size, including the terminating null character
+        let mut size = buffer.len() as minwindef::DWORD;
+        let ptr = if buffer.is_empty() {
+            NULL as _
+        } else {
+            buffer.as_mut_ptr()
+        };
+        let result = unsafe { sysinfoapi::GetComputerNameExW(kind, ptr, &mut size) };
+        if result != 0 {
+            buffer.truncate(size as usize);
+            // Name should not contain any nulls, but if it does, it ends at the first one
+            if let Some(end) = buffer.iter().position(|chr| *chr == 0) {
+                buffer.truncate(end);
+            }
+            return Ok(buffer);
+        }
 
-    if size > required_size {
-        // Should not happen, size "receives the number of TCHARs copied to the destination buffer, not including the terminating null character"
-        let e = std::io::Error::new(
-            std::io::ErrorKind::Other,
-            "Invalid value returned by GetComputerNameExW",
-        );
-        return Err(e.into());
+        let e = Error::last_os_error();
+        if e.raw_os_error() != Some(winerror::ERROR_MORE_DATA as i32) {
+            return Err(e.with_ffi("GetComputerNameExW"));
+        }
+        if size as usize <= buffer.len() {
+            // Should not happen, as the required size is bigger than the current buffer
+            let e = std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "Invalid value returned by GetComputerNameExW",
+            );
+            return Err(e.into());
+        }
+        buffer = vec![0; size as usize];
     }
-    // buffer[..size] is valid because buffer.len > size already
-    let str = String::from_utf16_lossy(&buffer[..(size as usize)]);
-    Ok(str)
 }
 
 fn get_computer_name() -> Result<String> {
-    get_value_from_get_computer_name_ex_w(ComputerNameDnsHostname)
+    get_computer_name_ex_w(ComputerNameDnsHostname).map(|name| String::from_utf16_lossy(&name))
 }
 
 fn get_computer_domain() -> Result<String> {
-    get_value_from_get_computer_name_ex_w(ComputerNameDnsDomain)
+    get_computer_name_ex_w(ComputerNameDnsDomain).map(|name| String::from_utf16_lossy(&name))
 }
 
 pub async fn platform() -> Result<Platform> {
@@ -192,3 +224,26 @@ pub async fn platform() -> Result<Platform> {
         build: format!("{}", version.dwBuildNumber),
     })
 }
+
+pub async fn hostname() -> Result<String> {
+    let name = get_computer_name_ex_w(ComputerNameDnsHostname)?;
+
+    String::from_utf16(&name).map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::release_name;
+
+    #[test]
+    fn test_release_name() {
+        assert_eq!(release_name(10, 0, 22631, true, 0), "11");
+        assert_eq!(release_name(10, 0, 19045, true, 0), "10");
+        assert_eq!(release_name(10, 0, 20348, false, 0), "2022");
+        assert_eq!(release_name(10, 0, 17763, false, 0), "2019");
+        assert_eq!(release_name(10, 0, 14393, false, 0), "2016");
+        assert_eq!(release_name(6, 3, 9600, false, 0), "2012 R2");
+        assert_eq!(release_name(6, 1, 7601, true, 0), "7");
+        assert_eq!(release_name(4, 0, 1381, true, 0), "unknown");
+    }
+}
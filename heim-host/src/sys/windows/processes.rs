@@ -0,0 +1,27 @@
+use std::mem;
+
+use winapi::shared::minwindef::DWORD;
+use winapi::um::psapi;
+
+use heim_common::prelude::*;
+
+fn performance_info() -> Result<psapi::PERFORMANCE_INFORMATION> {
+    let size = mem::size_of::<psapi::PERFORMANCE_INFORMATION>();
+    let mut info: psapi::PERFORMANCE_INFORMATION = unsafe { mem::zeroed() };
+    info.cb = size as DWORD;
+
+    let result = unsafe { psapi::GetPerformanceInfo(&mut info, size as DWORD) };
+    if result == 0 {
+        Err(Error::last_os_error().with_ffi("GetPerformanceInfo"))
+    } else {
+        Ok(info)
+    }
+}
+
+pub async fn process_count() -> Result<u64> {
+    performance_info().map(|info| u64::from(info.ProcessCount))
+}
+
+pub async fn thread_count() -> Result<u64> {
+    performance_info().map(|info| u64::from(info.ThreadCount))
+}
@@ -10,3 +10,8 @@ pub async fn uptime() -> Result<Time> {
 
     Ok(Time::new::<time::millisecond>(ms))
 }
+
+pub async fn uptime_monotonic() -> Result<Time> {
+    // `GetTickCount64` is monotonic already and includes time spent in sleep
+    uptime().await
+}
@@ -8,3 +8,22 @@ use crate::{sys, Time};
 pub async fn uptime() -> Result<Time> {
     sys::uptime().await
 }
+
+/// Returns [Time] amount from the system boot, measured with a monotonic clock.
+///
+/// Returned value is not affected by the system clock changes (ex. NTP adjustments),
+/// which makes it suitable for intervals measurement.
+///
+/// ## Compatibility
+///
+/// Platforms are differing in how the time spent in suspend is accounted:
+///
+/// * For Linux `CLOCK_BOOTTIME` clock is used, which **includes** time spent in suspend
+///   (as opposed to `CLOCK_MONOTONIC`, which does not)
+/// * For macOS `CLOCK_UPTIME_RAW` clock is used, which **does not include** time spent in sleep
+/// * For Windows `GetTickCount64` function is used, which **includes** time spent in sleep
+///
+/// [Time]: ./struct.Time.html
+pub async fn uptime_monotonic() -> Result<Time> {
+    sys::uptime_monotonic().await
+}
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::fmt;
 
 use crate::sys;
@@ -27,6 +28,21 @@ impl fmt::Debug for User {
     }
 }
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde_crate::Serialize for User {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde_crate::Serializer,
+    {
+        use serde_crate::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("User", 1)?;
+        state.serialize_field("username", self.username())?;
+        state.end()
+    }
+}
+
 /// Returns a stream over [User] sessions currently connected to the system.
 ///
 /// ## Compatibility
@@ -40,3 +56,38 @@ pub async fn users() -> Result<impl Stream<Item = Result<User>>> {
 
     Ok(inner.map_ok(Into::into))
 }
+
+/// Returns a stream over distinct usernames of the [users] currently connected to the system.
+///
+/// Same user might have multiple sessions opened (ex. via SSH),
+/// but each username will be yielded only once.
+///
+/// [users]: ./fn.users.html
+pub async fn users_unique() -> Result<impl Stream<Item = Result<String>>> {
+    let inner = users().await?;
+    let mut seen = HashSet::new();
+
+    Ok(inner.try_filter_map(move |user| {
+        let username = user.username();
+        let result = if seen.contains(username) {
+            None
+        } else {
+            let _ = seen.insert(username.to_string());
+            Some(username.to_string())
+        };
+
+        future::ok(result)
+    }))
+}
+
+/// Returns amount of the [user] sessions currently connected to the system.
+///
+/// See [users_unique] for the distinct users.
+///
+/// [user]: ./fn.users.html
+/// [users_unique]: ./fn.users_unique.html
+pub async fn user_count() -> Result<u64> {
+    let inner = users().await?;
+
+    inner.try_fold(0, |acc, _| future::ok(acc + 1)).await
+}
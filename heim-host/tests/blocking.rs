@@ -0,0 +1,46 @@
+#![cfg(feature = "blocking")]
+
+use heim_common::units::time;
+use heim_host as host;
+
+#[test]
+fn blocking_boot_time() {
+    let blocking = host::blocking::boot_time().unwrap();
+    let async_value = smol::block_on(host::boot_time()).unwrap();
+
+    assert_eq!(
+        blocking.get::<time::second>(),
+        async_value.get::<time::second>()
+    );
+}
+
+#[test]
+fn blocking_platform() {
+    let blocking = host::blocking::platform().unwrap();
+    let async_value = smol::block_on(host::platform()).unwrap();
+
+    assert_eq!(blocking.system(), async_value.system());
+    assert_eq!(blocking.release(), async_value.release());
+    assert_eq!(blocking.hostname(), async_value.hostname());
+}
+
+#[test]
+fn blocking_hostname() {
+    let blocking = host::blocking::hostname().unwrap();
+    let async_value = smol::block_on(host::hostname()).unwrap();
+
+    assert_eq!(blocking, async_value);
+}
+
+#[test]
+fn blocking_page_size() {
+    let blocking = host::blocking::page_size().unwrap();
+    let async_value = smol::block_on(host::page_size()).unwrap();
+
+    assert_eq!(blocking, async_value);
+}
+
+#[test]
+fn blocking_uptime() {
+    let _ = host::blocking::uptime().unwrap();
+}
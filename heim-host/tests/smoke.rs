@@ -21,6 +21,37 @@ async fn smoke_platform() {
     }
 }
 
+#[heim_derive::test]
+async fn smoke_hostname() {
+    let hostname = host::hostname().await.unwrap();
+    let platform = host::platform().await.unwrap();
+
+    assert_eq!(hostname, platform.hostname());
+}
+
+#[heim_derive::test]
+async fn smoke_page_size() {
+    let page_size = host::page_size().await.unwrap();
+
+    assert!(page_size.is_power_of_two());
+}
+
+#[heim_derive::test]
+async fn smoke_process_count() {
+    let count = host::process_count().await.unwrap();
+
+    assert!(count > 0);
+}
+
+#[heim_derive::test]
+async fn smoke_thread_count() {
+    match host::thread_count().await {
+        Ok(count) => assert!(count >= host::process_count().await.unwrap() / 2),
+        Err(e) if e.is_not_supported() => {}
+        Err(e) => panic!("{}", e),
+    }
+}
+
 #[heim_derive::test]
 async fn smoke_uptime() {
     let uptime = host::uptime().await;
@@ -28,6 +59,20 @@ async fn smoke_uptime() {
     assert!(uptime.unwrap().get::<time::second>() > 0.0);
 }
 
+#[heim_derive::test]
+async fn smoke_uptime_monotonic() {
+    let uptime = host::uptime_monotonic().await;
+
+    assert!(uptime.unwrap().get::<time::second>() > 0.0);
+}
+
+#[heim_derive::test]
+async fn smoke_is_elevated() {
+    let is_elevated = host::is_elevated().await;
+
+    assert!(is_elevated.is_ok());
+}
+
 #[heim_derive::test]
 async fn smoke_boot_time() {
     let boot_time = host::boot_time().await;
@@ -54,6 +99,7 @@ async fn smoke_users() {
             let _ = user.hostname();
             let _ = user.address();
             let _ = user.session_id();
+            assert!(user.login_time().get::<time::second>() > 0.0);
         }
 
         #[cfg(target_os = "macos")]
@@ -75,3 +121,69 @@ async fn smoke_users() {
         }
     }
 }
+
+#[heim_derive::test]
+async fn smoke_users_unique() {
+    let count = host::user_count().await.unwrap();
+    let unique = host::users_unique().await.unwrap();
+    let unique = unique.try_collect::<Vec<_>>().await.unwrap();
+
+    assert!(unique.len() as u64 <= count);
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "macos")]
+async fn smoke_model_identifier() {
+    let model = host::os::macos::model_identifier().await.unwrap();
+
+    assert!(!model.identifier().is_empty());
+    let _ = model.marketing_name();
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_open_fds() {
+    let (allocated, max) = host::os::linux::open_fds().await.unwrap();
+
+    assert!(allocated <= max);
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_kernel_modules() {
+    let modules = host::os::linux::kernel_modules().await.unwrap();
+    ::futures::pin_mut!(modules);
+    while let Some(module) = modules.next().await {
+        let module = module.unwrap();
+
+        let _ = module.name();
+        let _ = module.size();
+        let _ = module.use_count();
+        let _ = module.dependencies();
+        let _ = module.state();
+    }
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "macos")]
+async fn smoke_open_fds() {
+    let (allocated, max) = host::os::macos::open_fds().await.unwrap();
+
+    assert!(allocated <= max);
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "windows")]
+async fn smoke_product_id() {
+    let product_id = host::os::windows::product_id().await.unwrap();
+
+    assert!(!product_id.is_empty());
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "windows")]
+async fn smoke_install_date() {
+    let install_date = host::os::windows::install_date().await.unwrap();
+
+    assert!(install_date.get::<time::second>() > 0.0);
+}
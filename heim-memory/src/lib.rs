@@ -31,3 +31,13 @@ mod sys;
 
 pub use self::memory::*;
 pub use self::swap::*;
+
+/// Re-exports of the commonly used memory types and the measurement units for them.
+///
+/// ```rust
+/// use heim_memory::prelude::*;
+/// ```
+pub mod prelude {
+    pub use crate::{Memory, Swap};
+    pub use heim_common::units::{information, Information};
+}
@@ -1,9 +1,12 @@
 //! Linux-specific extensions
 
 /// Reference: https://gitlab.com/procps-ng/procps/blob/master/proc/sysinfo.c
+use std::fmt;
+
+use heim_common::prelude::*;
 use heim_common::units::{information, Information};
 
-use crate::Memory;
+use crate::{sys, Memory};
 
 /// Linux-specific extension to [`Memory`]
 pub trait MemoryExt {
@@ -69,3 +72,71 @@ impl MemoryExt for Memory {
         self.as_ref().inactive()
     }
 }
+
+/// Huge pages pool statistics for one particular page size.
+///
+/// See [`huge_pages`] function.
+///
+/// [`huge_pages`]: ./fn.huge_pages.html
+pub struct HugePages(sys::HugePages);
+
+wrap!(HugePages, sys::HugePages);
+
+impl HugePages {
+    /// Returns huge page size, ex. 2 MiB or 1 GiB.
+    pub fn size(&self) -> Information {
+        self.as_ref().size()
+    }
+
+    /// Returns amount of huge pages configured in the pool.
+    pub fn total(&self) -> u64 {
+        self.as_ref().total()
+    }
+
+    /// Returns amount of huge pages not yet allocated.
+    pub fn free(&self) -> u64 {
+        self.as_ref().free()
+    }
+
+    /// Returns amount of huge pages reserved for allocation, but not allocated yet.
+    pub fn reserved(&self) -> u64 {
+        self.as_ref().reserved()
+    }
+
+    /// Returns amount of huge pages allocated over the configured pool size.
+    pub fn surplus(&self) -> u64 {
+        self.as_ref().surplus()
+    }
+}
+
+impl fmt::Debug for HugePages {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("HugePages")
+            .field("size", &self.size())
+            .field("total", &self.total())
+            .field("free", &self.free())
+            .field("reserved", &self.reserved())
+            .field("surplus", &self.surplus())
+            .finish()
+    }
+}
+
+/// Returns system memory page size.
+///
+/// Value is fetched via `sysconf(_SC_PAGESIZE)` call once and cached after.
+/// It can be used to convert page counts reported by the `/proc` files into bytes.
+pub async fn page_size() -> Result<Information> {
+    sys::page_size().await
+}
+
+/// Returns [huge pages] statistics for each supported huge page size.
+///
+/// Values are read from the `/sys/kernel/mm/hugepages/` directory;
+/// if kernel has no huge pages support, empty vector is returned.
+///
+/// [huge pages]: ./struct.HugePages.html
+pub async fn huge_pages() -> Result<Vec<HugePages>> {
+    let inner = sys::huge_pages().await?;
+
+    Ok(inner.into_iter().map(Into::into).collect())
+}
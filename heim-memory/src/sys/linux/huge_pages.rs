@@ -0,0 +1,113 @@
+use std::io;
+use std::path::Path;
+
+use heim_common::prelude::*;
+use heim_common::units::{information, Information};
+use heim_runtime as rt;
+
+#[derive(Debug)]
+pub struct HugePages {
+    size: Information,
+    total: u64,
+    free: u64,
+    reserved: u64,
+    surplus: u64,
+}
+
+impl HugePages {
+    pub fn size(&self) -> Information {
+        self.size
+    }
+
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    pub fn free(&self) -> u64 {
+        self.free
+    }
+
+    pub fn reserved(&self) -> u64 {
+        self.reserved
+    }
+
+    pub fn surplus(&self) -> u64 {
+        self.surplus
+    }
+}
+
+// Directory names are looking like `hugepages-2048kB`, where `kB` stands for 1024 bytes
+fn parse_size(name: &str) -> Option<Information> {
+    let kibibytes = name
+        .strip_prefix("hugepages-")?
+        .strip_suffix("kB")?
+        .parse::<u64>()
+        .ok()?;
+
+    Some(Information::new::<information::kibibyte>(kibibytes))
+}
+
+async fn read_count(root: &Path, name: &str) -> Result<u64> {
+    let path = root.join(name);
+    let contents = rt::fs::read_to_string(&path)
+        .await
+        .map_err(|e| Error::from(e).with_file(&path))?;
+
+    Ok(contents.trim_end().parse::<u64>()?)
+}
+
+pub async fn page_size() -> Result<Information> {
+    let bytes = heim_common::sys::unix::page_size()?;
+
+    Ok(Information::new::<information::byte>(bytes))
+}
+
+pub async fn huge_pages() -> Result<Vec<HugePages>> {
+    let root = rt::linux::sysfs_root().join("kernel/mm/hugepages");
+    let entries = match rt::fs::read_dir(&root).await {
+        Ok(entries) => entries,
+        // Kernel was built without huge pages support
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(Error::from(e).with_file(root)),
+    };
+    rt::pin!(entries);
+
+    let mut acc = Vec::new();
+    while let Some(entry) = entries.next().await {
+        let entry = entry?;
+        let size = match entry.file_name().to_str().and_then(parse_size) {
+            Some(size) => size,
+            None => continue,
+        };
+        let path = entry.path();
+
+        acc.push(HugePages {
+            size,
+            total: read_count(&path, "nr_hugepages").await?,
+            free: read_count(&path, "free_hugepages").await?,
+            reserved: read_count(&path, "resv_hugepages").await?,
+            surplus: read_count(&path, "surplus_hugepages").await?,
+        });
+    }
+    acc.sort_by_key(|pages| pages.size);
+
+    Ok(acc)
+}
+
+#[cfg(test)]
+mod tests {
+    use heim_common::units::information;
+
+    use super::parse_size;
+
+    #[test]
+    fn test_parse_size() {
+        let size = parse_size("hugepages-2048kB").unwrap();
+        assert_eq!(size.get::<information::byte>(), 2 * 1024 * 1024);
+
+        let size = parse_size("hugepages-1048576kB").unwrap();
+        assert_eq!(size.get::<information::byte>(), 1024 * 1024 * 1024);
+
+        assert!(parse_size("hugepages").is_none());
+    }
+}
@@ -106,5 +106,7 @@ impl FromStr for Memory {
 }
 
 pub async fn memory() -> Result<Memory> {
+    rt::linux::ensure_procfs().await?;
+
     rt::fs::read_into(rt::linux::procfs_root().join("meminfo")).await
 }
@@ -1,5 +1,7 @@
+mod huge_pages;
 mod memory;
 mod swap;
 
+pub use self::huge_pages::*;
 pub use self::memory::*;
 pub use self::swap::*;
@@ -139,6 +139,8 @@ impl Swap {
 }
 
 pub async fn swap() -> Result<Swap> {
+    rt::linux::ensure_procfs().await?;
+
     rt::spawn_blocking(|| {
         let meminfo = fs::read_to_string(rt::linux::procfs_root().join("meminfo"))?;
         let vmstat = fs::read_to_string(rt::linux::procfs_root().join("vmstat"))?;
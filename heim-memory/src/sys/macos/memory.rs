@@ -1,7 +1,8 @@
 use heim_common::prelude::*;
+use heim_common::sys::unix;
 use heim_common::units::{information, Information};
 
-use super::{bindings, PAGE_SIZE};
+use super::bindings;
 
 #[derive(Debug)]
 pub struct Memory {
@@ -47,7 +48,7 @@ impl Memory {
 pub async fn memory() -> Result<Memory> {
     let total = bindings::hw_memsize()?;
     let vm_stats = bindings::host_vm_info()?;
-    let page_size = *PAGE_SIZE;
+    let page_size = unix::page_size()?;
 
     let total = Information::new::<information::byte>(total);
     let available = Information::new::<information::byte>(
@@ -1,7 +1,8 @@
 use heim_common::prelude::*;
+use heim_common::sys::unix;
 use heim_common::units::{information, Information};
 
-use super::{bindings, PAGE_SIZE};
+use super::bindings;
 
 #[derive(Debug)]
 pub struct Swap {
@@ -38,7 +39,7 @@ impl Swap {
 pub async fn swap() -> Result<Swap> {
     let xsw_usage = bindings::vm_swapusage()?;
     let vm_stats = bindings::host_vm_info()?;
-    let page_size = *PAGE_SIZE;
+    let page_size = unix::page_size()?;
 
     let total = Information::new::<information::byte>(u64::from(xsw_usage.xsu_total));
     let used = Information::new::<information::byte>(u64::from(xsw_usage.xsu_used));
@@ -50,3 +50,25 @@ async fn smoke_swap() {
         let _ = swap.sout();
     }
 }
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_page_size() {
+    let page_size = memory::os::linux::page_size().await;
+
+    assert!(page_size.unwrap().get::<information::byte>() > 0);
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_huge_pages() {
+    let huge_pages = memory::os::linux::huge_pages().await.unwrap();
+
+    for pages in huge_pages {
+        assert!(pages.size().get::<information::byte>() > 0);
+        let _ = pages.total();
+        let _ = pages.free();
+        let _ = pages.reserved();
+        let _ = pages.surplus();
+    }
+}
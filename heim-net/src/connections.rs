@@ -0,0 +1,134 @@
+use std::net::SocketAddr;
+
+use heim_common::prelude::*;
+use heim_common::Pid;
+
+use crate::sys;
+
+/// Connection protocol.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ConnectionProtocol {
+    /// TCP over IPv4.
+    Tcp,
+    /// TCP over IPv6.
+    Tcp6,
+    /// UDP over IPv4.
+    Udp,
+    /// UDP over IPv6.
+    Udp6,
+}
+
+impl ConnectionProtocol {
+    /// Returns `true` if it is a TCP protocol, either over IPv4 or IPv6.
+    pub fn is_tcp(&self) -> bool {
+        matches!(self, ConnectionProtocol::Tcp | ConnectionProtocol::Tcp6)
+    }
+
+    /// Returns `true` if it is an IPv6 protocol.
+    pub fn is_ipv6(&self) -> bool {
+        matches!(self, ConnectionProtocol::Tcp6 | ConnectionProtocol::Udp6)
+    }
+}
+
+/// TCP connection state.
+///
+/// See RFC 793 for the states description.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum TcpState {
+    /// `ESTABLISHED` state.
+    Established,
+    /// `SYN-SENT` state.
+    SynSent,
+    /// `SYN-RECEIVED` state.
+    SynReceived,
+    /// `FIN-WAIT-1` state.
+    FinWait1,
+    /// `FIN-WAIT-2` state.
+    FinWait2,
+    /// `TIME-WAIT` state.
+    TimeWait,
+    /// `CLOSED` state.
+    Closed,
+    /// `CLOSE-WAIT` state.
+    CloseWait,
+    /// `LAST-ACK` state.
+    LastAck,
+    /// `LISTEN` state.
+    Listen,
+    /// `CLOSING` state.
+    Closing,
+}
+
+/// Network connection (socket) information.
+///
+/// See [connections] function.
+///
+/// [connections]: ./fn.connections.html
+#[derive(Debug, Clone)]
+pub struct Connection {
+    pub(crate) protocol: ConnectionProtocol,
+    pub(crate) local: SocketAddr,
+    pub(crate) remote: SocketAddr,
+    pub(crate) state: Option<TcpState>,
+    pub(crate) inode: Option<u64>,
+    pub(crate) pid: Option<Pid>,
+}
+
+impl Connection {
+    /// Returns connection protocol.
+    pub fn protocol(&self) -> ConnectionProtocol {
+        self.protocol
+    }
+
+    /// Returns local socket address.
+    pub fn local(&self) -> SocketAddr {
+        self.local
+    }
+
+    /// Returns remote (peer) socket address.
+    ///
+    /// Unspecified address with zero port is returned for not connected sockets,
+    /// ex. for the listening TCP sockets.
+    pub fn remote(&self) -> SocketAddr {
+        self.remote
+    }
+
+    /// Returns TCP connection state.
+    ///
+    /// `None` is returned for UDP sockets.
+    pub fn state(&self) -> Option<TcpState> {
+        self.state
+    }
+
+    /// Returns socket inode number, if available.
+    pub fn inode(&self) -> Option<u64> {
+        self.inode
+    }
+
+    /// Returns [Pid] of the process which owns this socket, if it can be resolved.
+    ///
+    /// [Pid]: ../heim_common/type.Pid.html
+    pub fn pid(&self) -> Option<Pid> {
+        self.pid
+    }
+}
+
+/// Returns a stream over the system-wide TCP and UDP [connections].
+///
+/// ## Compatibility
+///
+/// * For Linux connections are parsed from the `/proc/net/{tcp,tcp6,udp,udp6}` files;
+///   owning process is resolved by scanning the `/proc/{pid}/fd/` directories,
+///   `None` is returned for processes which are not accessible for the current user.
+/// * For macOS, FreeBSD and Windows this function returns an error, as it is not implemented yet,
+///   [`Error::is_not_supported`] returns `true` for it
+///
+/// [connections]: ./struct.Connection.html
+/// [`Error::is_not_supported`]: ../heim_common/struct.Error.html#method.is_not_supported
+pub async fn connections() -> Result<impl Stream<Item = Result<Connection>>> {
+    let connections = sys::connections().await?;
+
+    Ok(stream::iter(connections.into_iter().map(Ok)))
+}
@@ -1,7 +1,8 @@
 use std::fmt;
 
 use heim_common::prelude::*;
-use heim_common::units::Information;
+use heim_common::units::{Information, InformationRate, Time};
+use heim_common::utils::delta;
 
 use crate::sys;
 
@@ -56,6 +57,25 @@ impl IoCounters {
     pub fn drop_recv(&self) -> u64 {
         self.as_ref().drop_recv()
     }
+
+    /// Calculates [IO rates] between the `previous` sample of the same interface counters
+    /// and this one, taken `elapsed` time after it.
+    ///
+    /// If some counter was reset or had wrapped around between the samples,
+    /// its rate is clamped to zero.
+    ///
+    /// [IO rates]: struct.IoRates.html
+    pub fn rate_since(&self, previous: &IoCounters, elapsed: Time) -> IoRates {
+        IoRates {
+            bytes_sent: delta::information_rate(self.bytes_sent(), previous.bytes_sent(), elapsed),
+            bytes_recv: delta::information_rate(self.bytes_recv(), previous.bytes_recv(), elapsed),
+            packets_sent: delta::per_second(self.packets_sent(), previous.packets_sent(), elapsed),
+            packets_recv: delta::per_second(self.packets_recv(), previous.packets_recv(), elapsed),
+            errors_sent: delta::per_second(self.errors_sent(), previous.errors_sent(), elapsed),
+            errors_recv: delta::per_second(self.errors_recv(), previous.errors_recv(), elapsed),
+            drop_recv: delta::per_second(self.drop_recv(), previous.drop_recv(), elapsed),
+        }
+    }
 }
 
 impl fmt::Debug for IoCounters {
@@ -73,6 +93,83 @@ impl fmt::Debug for IoCounters {
     }
 }
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde_crate::Serialize for IoCounters {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde_crate::Serializer,
+    {
+        use heim_common::serialize::Quantity;
+        use serde_crate::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("IoCounters", 8)?;
+        state.serialize_field("interface", self.interface())?;
+        state.serialize_field("bytes_sent", &Quantity(&self.bytes_sent()))?;
+        state.serialize_field("bytes_recv", &Quantity(&self.bytes_recv()))?;
+        state.serialize_field("packets_sent", &self.packets_sent())?;
+        state.serialize_field("packets_recv", &self.packets_recv())?;
+        state.serialize_field("errors_sent", &self.errors_sent())?;
+        state.serialize_field("errors_recv", &self.errors_recv())?;
+        state.serialize_field("drop_recv", &self.drop_recv())?;
+        state.end()
+    }
+}
+
+/// Network device I/O rates, calculated between two [IO counters] samples.
+///
+/// See [`IoCounters::rate_since`] method.
+///
+/// [IO counters]: struct.IoCounters.html
+/// [`IoCounters::rate_since`]: struct.IoCounters.html#method.rate_since
+#[derive(Debug, Clone, Copy)]
+pub struct IoRates {
+    bytes_sent: InformationRate,
+    bytes_recv: InformationRate,
+    packets_sent: f64,
+    packets_recv: f64,
+    errors_sent: f64,
+    errors_recv: f64,
+    drop_recv: f64,
+}
+
+impl IoRates {
+    /// Returns sending information rate.
+    pub fn bytes_sent(&self) -> InformationRate {
+        self.bytes_sent
+    }
+
+    /// Returns receiving information rate.
+    pub fn bytes_recv(&self) -> InformationRate {
+        self.bytes_recv
+    }
+
+    /// Returns packets amount sent per second.
+    pub fn packets_sent(&self) -> f64 {
+        self.packets_sent
+    }
+
+    /// Returns packets amount received per second.
+    pub fn packets_recv(&self) -> f64 {
+        self.packets_recv
+    }
+
+    /// Returns errors amount per second, which had occurred while sending data.
+    pub fn errors_sent(&self) -> f64 {
+        self.errors_sent
+    }
+
+    /// Returns errors amount per second, which had occurred while receiving data.
+    pub fn errors_recv(&self) -> f64 {
+        self.errors_recv
+    }
+
+    /// Returns packets amount per second, which were dropped while receiving them.
+    pub fn drop_recv(&self) -> f64 {
+        self.drop_recv
+    }
+}
+
 /// Returns a stream over the [IO counters] for each network interface.
 ///
 /// ## Compatibility
@@ -85,3 +182,19 @@ pub async fn io_counters() -> Result<impl Stream<Item = Result<IoCounters>>> {
 
     Ok(inner.map_ok(Into::into))
 }
+
+/// Returns a stream over the [IO counters] for each physical network interface.
+///
+/// Loopback and virtual interfaces (ex. `lo`, `veth*` or `docker0`) are skipped.
+///
+/// ## Compatibility
+///
+/// For Linux interfaces without the underlying device in `/sys/class/net` are skipped,
+/// for other platforms virtual interfaces are guessed by their names.
+///
+/// [IO counters]: struct.IoCounters.html
+pub async fn io_counters_physical() -> Result<impl Stream<Item = Result<IoCounters>>> {
+    let inner = sys::io_counters_physical().await?;
+
+    Ok(inner.map_ok(Into::into))
+}
@@ -27,11 +27,30 @@
 pub mod os;
 mod sys;
 
+mod connections;
 mod counters;
 mod nic;
+mod protocol;
 
+pub use self::connections::*;
 pub use self::counters::*;
 pub use self::nic::*;
+pub use self::protocol::*;
 
 // Re-exports
 pub use macaddr::{MacAddr, MacAddr6, MacAddr8};
+
+/// Re-exports of the commonly used network types and the measurement units for them.
+///
+/// ```rust
+/// use heim_net::prelude::*;
+/// ```
+pub mod prelude {
+    pub use crate::{
+        Address, Connection, ConnectionProtocol, IcmpCounters, IoCounters, IoRates, IpCounters,
+        MacAddr, Nic, ProtocolCounters, TcpCounters, TcpState, UdpCounters,
+    };
+    pub use heim_common::units::{
+        information, information_rate, time, Information, InformationRate, Time,
+    };
+}
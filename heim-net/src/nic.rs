@@ -1,9 +1,11 @@
+use std::collections::HashMap;
 use std::fmt;
 use std::net;
 
 use heim_common::prelude::*;
+use macaddr::MacAddr6;
 
-use crate::sys;
+use crate::{sys, IoCounters};
 
 /// Network interface address.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
@@ -19,6 +21,25 @@ pub enum Address {
     Link(macaddr::MacAddr),
 }
 
+impl Address {
+    /// Returns IPv6 address scope (zone) ID, ex. `2` for the `fe80::1%2` address.
+    ///
+    /// Scope ID is required to connect to the link-local addresses (`fe80::/10`)
+    /// and it is usually equal to the [`Nic::index`] of the interface that address belongs to.
+    ///
+    /// ## Returns
+    ///
+    /// Returns `None` for non-IPv6 addresses or if address has no scope ID.
+    ///
+    /// [`Nic::index`]: ./struct.Nic.html#method.index
+    pub fn scope_id(&self) -> Option<u32> {
+        match self {
+            Address::Inet6(addr) if addr.scope_id() != 0 => Some(addr.scope_id()),
+            _ => None,
+        }
+    }
+}
+
 // TODO: Consider implement `Address::to_family()` method
 // which will return the `libc::c_int` value with a corresponding value
 // for the current address member (ex. `AF_INET` or `AF_PACKET`)
@@ -40,6 +61,19 @@ impl Nic {
         self.as_ref().index()
     }
 
+    /// Returns NIC hardware (MAC) address, if available.
+    ///
+    /// Since NICs with multiple addresses are enumerated once per address,
+    /// the same hardware address is returned for all of them.
+    ///
+    /// ## Returns
+    ///
+    /// `None` is returned for interfaces without hardware address,
+    /// ex. for tunnels, and for interfaces with non-EUI-48 hardware addresses.
+    pub fn mac_address(&self) -> Option<MacAddr6> {
+        self.as_ref().mac_address()
+    }
+
     /// Returns primary NIC address.
     ///
     /// See [`nic`] for more info regarding NICs that have multiple addresses
@@ -47,6 +81,15 @@ impl Nic {
         self.as_ref().address()
     }
 
+    /// Returns primary NIC address scope ID, if available.
+    ///
+    /// See [`Address::scope_id`] for details.
+    ///
+    /// [`Address::scope_id`]: ./enum.Address.html#method.scope_id
+    pub fn scope_id(&self) -> Option<u32> {
+        self.address().scope_id()
+    }
+
     /// Returns netmask address if available.
     pub fn netmask(&self) -> Option<Address> {
         self.as_ref().netmask()
@@ -76,18 +119,30 @@ impl Nic {
     pub fn is_multicast(&self) -> bool {
         self.as_ref().is_multicast()
     }
+
+    /// Returns `bool` indicating whether interface is in promiscuous mode.
+    ///
+    /// ## Compatibility
+    ///
+    /// At the moment this information is not available for Windows
+    /// and `false` is always returned.
+    pub fn is_promiscuous(&self) -> bool {
+        self.as_ref().is_promiscuous()
+    }
 }
 
 impl fmt::Debug for Nic {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("Nic")
             .field("name", &self.name())
+            .field("mac_address", &self.mac_address())
             .field("address", &self.address())
             .field("netmask", &self.netmask())
             .field("destination", &self.destination())
             .field("is_up", &self.is_up())
             .field("is_loopback", &self.is_loopback())
             .field("is_multicast", &self.is_multicast())
+            .field("is_promiscuous", &self.is_promiscuous())
             .finish()
     }
 }
@@ -97,8 +152,50 @@ impl fmt::Debug for Nic {
 /// [Network Interface Cards]: struct.Nic.html
 ///
 /// Depending on your platform, NICs that have multiple addresses may be enumerated several times, with a different [`address`](Nic::address) every time.
+///
+/// For Unix systems NICs are enumerated via `getifaddrs(3)`, which yields both Internet
+/// and link-level entries; link-level entries are yielded too, with an [`Address::Link`] address,
+/// and their hardware address is also available via [`Nic::mac_address`] for all entries
+/// of the same interface. Entries with unsupported address families are skipped.
+///
+/// [`Address::Link`]: enum.Address.html#variant.Link
+/// [`Nic::mac_address`]: struct.Nic.html#method.mac_address
 pub async fn nic() -> Result<impl Stream<Item = Result<Nic>> + Send + Sync> {
     let inner = sys::nic().await?;
 
     Ok(inner.map_ok(Into::into))
 }
+
+/// Returns a stream over the [Network Interface Cards] along with their [IO counters].
+///
+/// Same as [`nic`], but joins each yielded NIC with the corresponding row from
+/// the [`io_counters`] stream, so there is no need to match them by name manually.
+///
+/// Similar to [`nic`], NICs that have multiple addresses may be enumerated several times,
+/// each time with the same IO counters.
+/// NICs without IO counters available are yielded with zeroed counters.
+///
+/// ## Compatibility
+///
+/// Since IO counters are not implemented for Windows yet, they are always zeroed there.
+///
+/// [Network Interface Cards]: struct.Nic.html
+/// [IO counters]: struct.IoCounters.html
+/// [`io_counters`]: fn.io_counters.html
+pub async fn nics_with_counters() -> Result<impl Stream<Item = Result<(Nic, IoCounters)>>> {
+    let counters = sys::io_counters()
+        .await?
+        .map_ok(|counters| (counters.interface().to_string(), counters))
+        .try_collect::<HashMap<_, _>>()
+        .await?;
+    let inner = sys::nic().await?;
+
+    Ok(inner.map_ok(move |nic| {
+        let nic_counters = match counters.get(nic.name()) {
+            Some(counters) => counters.clone(),
+            None => sys::IoCounters::empty(nic.name()),
+        };
+
+        (nic.into(), nic_counters.into())
+    }))
+}
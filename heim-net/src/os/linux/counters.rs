@@ -22,6 +22,26 @@ impl IoCountersExt for crate::IoCounters {
     }
 }
 
+/// Returns a stream over the [IO counters] for each network interface,
+/// preferring 64-bit values from the `/sys/class/net/{interface}/statistics/` directory.
+///
+/// Depending on the kernel version and network driver, `/proc/net/dev` values,
+/// which are used by the [io_counters] function, might be 32-bit wide;
+/// these counters wrap around pretty fast for the high-speed interfaces
+/// and make rate calculation incorrect.
+///
+/// Bytes, packets, errors and drops counters are replaced with the `sysfs` values
+/// if corresponding files exist; otherwise `/proc/net/dev` values are used.
+///
+/// [IO counters]: ../../struct.IoCounters.html
+/// [io_counters]: ../../fn.io_counters.html
+#[cfg(target_os = "linux")]
+pub async fn io_counters_wide() -> Result<impl Stream<Item = Result<IoCounters>>> {
+    let inner = sys::io_counters_wide().await?;
+
+    Ok(inner.map_ok(Into::into))
+}
+
 /// Returns stream which yield [IO counters] for each network interface for process with given `pid`.
 ///
 /// **MUST** be used as `process::Process::net_io_counters()`
@@ -0,0 +1,293 @@
+use heim_common::prelude::*;
+
+use crate::sys;
+
+/// IP protocol counters.
+#[derive(Debug, Clone, Default)]
+pub struct IpCounters {
+    pub(crate) in_receives: u64,
+    pub(crate) in_hdr_errors: u64,
+    pub(crate) in_addr_errors: u64,
+    pub(crate) forw_datagrams: u64,
+    pub(crate) in_discards: u64,
+    pub(crate) in_delivers: u64,
+    pub(crate) out_requests: u64,
+    pub(crate) out_discards: u64,
+    pub(crate) out_no_routes: u64,
+}
+
+impl IpCounters {
+    /// Returns total amount of received datagrams, including the erroneous ones.
+    pub fn in_receives(&self) -> u64 {
+        self.in_receives
+    }
+
+    /// Returns amount of received datagrams discarded due to the header errors.
+    pub fn in_hdr_errors(&self) -> u64 {
+        self.in_hdr_errors
+    }
+
+    /// Returns amount of received datagrams discarded due to the invalid destination address.
+    pub fn in_addr_errors(&self) -> u64 {
+        self.in_addr_errors
+    }
+
+    /// Returns amount of forwarded datagrams.
+    pub fn forw_datagrams(&self) -> u64 {
+        self.forw_datagrams
+    }
+
+    /// Returns amount of received datagrams discarded for other reasons (ex. lack of buffer space).
+    pub fn in_discards(&self) -> u64 {
+        self.in_discards
+    }
+
+    /// Returns amount of received datagrams delivered to the upper-layer protocols.
+    pub fn in_delivers(&self) -> u64 {
+        self.in_delivers
+    }
+
+    /// Returns amount of datagrams supplied by the upper-layer protocols for transmission.
+    pub fn out_requests(&self) -> u64 {
+        self.out_requests
+    }
+
+    /// Returns amount of outgoing datagrams discarded for other reasons (ex. lack of buffer space).
+    pub fn out_discards(&self) -> u64 {
+        self.out_discards
+    }
+
+    /// Returns amount of outgoing datagrams discarded because no route was found.
+    pub fn out_no_routes(&self) -> u64 {
+        self.out_no_routes
+    }
+}
+
+/// ICMP protocol counters.
+#[derive(Debug, Clone, Default)]
+pub struct IcmpCounters {
+    pub(crate) in_msgs: u64,
+    pub(crate) in_errors: u64,
+    pub(crate) in_dest_unreachs: u64,
+    pub(crate) out_msgs: u64,
+    pub(crate) out_errors: u64,
+    pub(crate) out_dest_unreachs: u64,
+}
+
+impl IcmpCounters {
+    /// Returns total amount of received messages.
+    pub fn in_msgs(&self) -> u64 {
+        self.in_msgs
+    }
+
+    /// Returns amount of received messages with ICMP-specific errors.
+    pub fn in_errors(&self) -> u64 {
+        self.in_errors
+    }
+
+    /// Returns amount of received "Destination Unreachable" messages.
+    pub fn in_dest_unreachs(&self) -> u64 {
+        self.in_dest_unreachs
+    }
+
+    /// Returns total amount of messages attempted to send.
+    pub fn out_msgs(&self) -> u64 {
+        self.out_msgs
+    }
+
+    /// Returns amount of messages not sent due to ICMP-specific errors.
+    pub fn out_errors(&self) -> u64 {
+        self.out_errors
+    }
+
+    /// Returns amount of sent "Destination Unreachable" messages.
+    pub fn out_dest_unreachs(&self) -> u64 {
+        self.out_dest_unreachs
+    }
+}
+
+/// TCP protocol counters.
+#[derive(Debug, Clone, Default)]
+pub struct TcpCounters {
+    pub(crate) active_opens: u64,
+    pub(crate) passive_opens: u64,
+    pub(crate) attempt_fails: u64,
+    pub(crate) estab_resets: u64,
+    pub(crate) curr_estab: u64,
+    pub(crate) in_segs: u64,
+    pub(crate) out_segs: u64,
+    pub(crate) retrans_segs: u64,
+    pub(crate) in_errs: u64,
+    pub(crate) out_rsts: u64,
+    pub(crate) listen_overflows: Option<u64>,
+    pub(crate) listen_drops: Option<u64>,
+    pub(crate) timeouts: Option<u64>,
+}
+
+impl TcpCounters {
+    /// Returns amount of the active connection openings (`SYN-SENT` state transitions).
+    pub fn active_opens(&self) -> u64 {
+        self.active_opens
+    }
+
+    /// Returns amount of the passive connection openings (`SYN-RCVD` state transitions).
+    pub fn passive_opens(&self) -> u64 {
+        self.passive_opens
+    }
+
+    /// Returns amount of failed connection attempts.
+    pub fn attempt_fails(&self) -> u64 {
+        self.attempt_fails
+    }
+
+    /// Returns amount of resets of the established connections.
+    pub fn estab_resets(&self) -> u64 {
+        self.estab_resets
+    }
+
+    /// Returns amount of currently established connections.
+    pub fn curr_estab(&self) -> u64 {
+        self.curr_estab
+    }
+
+    /// Returns total amount of received segments, including the erroneous ones.
+    pub fn in_segs(&self) -> u64 {
+        self.in_segs
+    }
+
+    /// Returns total amount of sent segments, excluding the retransmitted ones.
+    pub fn out_segs(&self) -> u64 {
+        self.out_segs
+    }
+
+    /// Returns amount of retransmitted segments.
+    pub fn retrans_segs(&self) -> u64 {
+        self.retrans_segs
+    }
+
+    /// Returns amount of segments received in error.
+    pub fn in_errs(&self) -> u64 {
+        self.in_errs
+    }
+
+    /// Returns amount of sent segments with the `RST` flag.
+    pub fn out_rsts(&self) -> u64 {
+        self.out_rsts
+    }
+
+    /// Returns amount of times the listen queue of a socket overflowed, if available.
+    pub fn listen_overflows(&self) -> Option<u64> {
+        self.listen_overflows
+    }
+
+    /// Returns amount of connection requests dropped by the listening sockets, if available.
+    pub fn listen_drops(&self) -> Option<u64> {
+        self.listen_drops
+    }
+
+    /// Returns amount of retransmission timeouts, if available.
+    pub fn timeouts(&self) -> Option<u64> {
+        self.timeouts
+    }
+}
+
+/// UDP protocol counters.
+#[derive(Debug, Clone, Default)]
+pub struct UdpCounters {
+    pub(crate) in_datagrams: u64,
+    pub(crate) no_ports: u64,
+    pub(crate) in_errors: u64,
+    pub(crate) out_datagrams: u64,
+    pub(crate) rcvbuf_errors: u64,
+    pub(crate) sndbuf_errors: u64,
+}
+
+impl UdpCounters {
+    /// Returns amount of datagrams delivered to the UDP users.
+    pub fn in_datagrams(&self) -> u64 {
+        self.in_datagrams
+    }
+
+    /// Returns amount of received datagrams for which there was no application at the destination port.
+    pub fn no_ports(&self) -> u64 {
+        self.no_ports
+    }
+
+    /// Returns amount of received datagrams which could not be delivered for other reasons.
+    pub fn in_errors(&self) -> u64 {
+        self.in_errors
+    }
+
+    /// Returns amount of sent datagrams.
+    pub fn out_datagrams(&self) -> u64 {
+        self.out_datagrams
+    }
+
+    /// Returns amount of received datagrams dropped due to the receive buffer overflow.
+    pub fn rcvbuf_errors(&self) -> u64 {
+        self.rcvbuf_errors
+    }
+
+    /// Returns amount of datagrams dropped due to the send buffer overflow.
+    pub fn sndbuf_errors(&self) -> u64 {
+        self.sndbuf_errors
+    }
+}
+
+/// Network stack counters, aggregated for each protocol.
+#[derive(Debug, Clone, Default)]
+pub struct ProtocolCounters {
+    pub(crate) ip: IpCounters,
+    pub(crate) icmp: IcmpCounters,
+    pub(crate) tcp: TcpCounters,
+    pub(crate) udp: UdpCounters,
+}
+
+impl ProtocolCounters {
+    /// Returns [IP] protocol counters.
+    ///
+    /// [IP]: ./struct.IpCounters.html
+    pub fn ip(&self) -> &IpCounters {
+        &self.ip
+    }
+
+    /// Returns [ICMP] protocol counters.
+    ///
+    /// [ICMP]: ./struct.IcmpCounters.html
+    pub fn icmp(&self) -> &IcmpCounters {
+        &self.icmp
+    }
+
+    /// Returns [TCP] protocol counters.
+    ///
+    /// [TCP]: ./struct.TcpCounters.html
+    pub fn tcp(&self) -> &TcpCounters {
+        &self.tcp
+    }
+
+    /// Returns [UDP] protocol counters.
+    ///
+    /// [UDP]: ./struct.UdpCounters.html
+    pub fn udp(&self) -> &UdpCounters {
+        &self.udp
+    }
+}
+
+/// Returns system-wide network stack [counters] for each protocol.
+///
+/// Unlike the per-interface [I/O counters], these values are revealing the network stack health,
+/// ex. amount of TCP retransmissions or UDP receive errors.
+///
+/// ## Compatibility
+///
+/// * For Linux counters are parsed from the `/proc/net/snmp` file;
+///   some extra TCP counters are available from the `/proc/net/netstat` file
+/// * For macOS, FreeBSD and Windows this function returns an error, as it is not implemented yet,
+///   [`Error::is_not_supported`] returns `true` for it
+///
+/// [counters]: ./struct.ProtocolCounters.html
+/// [I/O counters]: ./struct.IoCounters.html
+/// [`Error::is_not_supported`]: ../heim_common/struct.Error.html#method.is_not_supported
+pub async fn protocol_counters() -> Result<ProtocolCounters> {
+    sys::protocol_counters().await
+}
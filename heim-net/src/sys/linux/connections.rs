@@ -0,0 +1,270 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::Path;
+
+use heim_common::prelude::*;
+use heim_common::Pid;
+use heim_runtime as rt;
+
+use crate::{Connection, ConnectionProtocol, TcpState};
+
+// https://github.com/torvalds/linux/blob/master/include/net/tcp_states.h
+fn tcp_state(value: u8) -> Option<TcpState> {
+    match value {
+        0x01 => Some(TcpState::Established),
+        0x02 => Some(TcpState::SynSent),
+        // `TCP_NEW_SYN_RECV` (0x0C) is a kernel-internal variant of the same state
+        0x03 | 0x0C => Some(TcpState::SynReceived),
+        0x04 => Some(TcpState::FinWait1),
+        0x05 => Some(TcpState::FinWait2),
+        0x06 => Some(TcpState::TimeWait),
+        0x07 => Some(TcpState::Closed),
+        0x08 => Some(TcpState::CloseWait),
+        0x09 => Some(TcpState::LastAck),
+        0x0A => Some(TcpState::Listen),
+        0x0B => Some(TcpState::Closing),
+        _ => None,
+    }
+}
+
+// Address is printed as a sequence of 32-bit words in the host byte order,
+// while each word holds network-ordered bytes, ex. `0100007F` for `127.0.0.1`
+// on the little-endian machines; port is printed in the host byte order.
+fn parse_addr(value: &str) -> Result<SocketAddr> {
+    let mut parts = value.split(':');
+    let (addr, port) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(addr), Some(port), None) if addr.len() == 8 || addr.len() == 32 => (addr, port),
+        _ => {
+            let inner = io::Error::from(io::ErrorKind::InvalidData);
+            return Err(Error::from(inner).with_message(format!("Malformed address {:?}", value)));
+        }
+    };
+
+    let mut octets = Vec::with_capacity(16);
+    for offset in (0..addr.len()).step_by(8) {
+        let word = u32::from_str_radix(&addr[offset..offset + 8], 16)?;
+        octets.extend_from_slice(&word.to_ne_bytes());
+    }
+    let ip = if octets.len() == 4 {
+        IpAddr::V4(Ipv4Addr::new(octets[0], octets[1], octets[2], octets[3]))
+    } else {
+        let mut raw = [0u8; 16];
+        raw.copy_from_slice(&octets);
+        IpAddr::V6(Ipv6Addr::from(raw))
+    };
+
+    Ok(SocketAddr::new(ip, u16::from_str_radix(port, 16)?))
+}
+
+// Line layout is
+// `sl local_address rem_address st tx_queue:rx_queue tr:tm->when retrnsmt uid timeout inode ...`
+fn parse_line(line: &str, protocol: ConnectionProtocol) -> Result<Connection> {
+    let parts = line.split_whitespace().collect::<Vec<_>>();
+    if parts.len() < 10 {
+        return Err(Error::missing_key("inode", "/proc/net"));
+    }
+
+    let state = if protocol.is_tcp() {
+        tcp_state(u8::from_str_radix(parts[3], 16)?)
+    } else {
+        None
+    };
+    // Zero inode is used for the sockets which are not bound to any file (ex. in `TIME_WAIT` state)
+    let inode = Some(parts[9].parse::<u64>()?).filter(|inode| *inode != 0);
+
+    Ok(Connection {
+        protocol,
+        local: parse_addr(parts[1])?,
+        remote: parse_addr(parts[2])?,
+        state,
+        inode,
+        pid: None,
+    })
+}
+
+fn read_table(path: &Path, protocol: ConnectionProtocol, acc: &mut Vec<Connection>) -> Result<()> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        // IPv6 tables are missing if IPv6 support is disabled
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(Error::from(e).with_file(path)),
+    };
+
+    // First line is a header
+    for line in contents.lines().skip(1) {
+        acc.push(parse_line(line, protocol)?);
+    }
+
+    Ok(())
+}
+
+// Socket file descriptors are symlinks to the `socket:[<inode>]` pseudo-files
+fn socket_inode(target: &Path) -> Option<u64> {
+    target
+        .to_str()?
+        .strip_prefix("socket:[")?
+        .strip_suffix(']')?
+        .parse()
+        .ok()
+}
+
+// Best-effort mapping; processes of other users are not accessible
+// without the elevated privileges and they are silently skipped.
+fn socket_owners() -> HashMap<u64, Pid> {
+    let mut owners = HashMap::new();
+    let entries = match fs::read_dir(rt::linux::procfs_root()) {
+        Ok(entries) => entries,
+        Err(_) => return owners,
+    };
+
+    for entry in entries.flatten() {
+        let pid = match entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.parse::<Pid>().ok())
+        {
+            Some(pid) => pid,
+            None => continue,
+        };
+        let fds = match fs::read_dir(entry.path().join("fd")) {
+            Ok(fds) => fds,
+            Err(_) => continue,
+        };
+        for fd in fds.flatten() {
+            if let Some(inode) = fs::read_link(fd.path())
+                .ok()
+                .as_deref()
+                .and_then(socket_inode)
+            {
+                let _ = owners.entry(inode).or_insert(pid);
+            }
+        }
+    }
+
+    owners
+}
+
+fn read_connections() -> Result<Vec<Connection>> {
+    let root = rt::linux::procfs_root().join("net");
+    let mut acc = Vec::new();
+    for (name, protocol) in [
+        ("tcp", ConnectionProtocol::Tcp),
+        ("tcp6", ConnectionProtocol::Tcp6),
+        ("udp", ConnectionProtocol::Udp),
+        ("udp6", ConnectionProtocol::Udp6),
+    ]
+    .iter()
+    {
+        read_table(&root.join(name), *protocol, &mut acc)?;
+    }
+
+    if !acc.is_empty() {
+        let owners = socket_owners();
+        for connection in acc.iter_mut() {
+            connection.pid = connection
+                .inode
+                .and_then(|inode| owners.get(&inode).copied());
+        }
+    }
+
+    Ok(acc)
+}
+
+pub async fn connections() -> Result<Vec<Connection>> {
+    rt::linux::ensure_procfs().await?;
+
+    rt::spawn_blocking(read_connections).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+    use std::path::Path;
+
+    use super::{parse_addr, parse_line, socket_inode, tcp_state};
+    use crate::{ConnectionProtocol, TcpState};
+
+    // Tables are always printed in the host byte order,
+    // so the test fixtures are converted to it too.
+    fn hex_word(octets: [u8; 4]) -> String {
+        format!("{:08X}", u32::from_ne_bytes(octets))
+    }
+
+    #[test]
+    fn test_parse_ipv4_addr() {
+        let value = format!("{}:0277", hex_word([127, 0, 0, 1]));
+        let expected = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 631);
+
+        assert_eq!(parse_addr(&value).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_parse_ipv6_addr() {
+        let value = format!(
+            "{}{}{}{}:1F90",
+            hex_word([0, 0, 0, 0]),
+            hex_word([0, 0, 0, 0]),
+            hex_word([0, 0, 0, 0]),
+            hex_word([0, 0, 0, 1]),
+        );
+        let expected = SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), 8080);
+
+        assert_eq!(parse_addr(&value).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_parse_malformed_addr() {
+        assert!(parse_addr("0100007F").is_err());
+        assert!(parse_addr("0100:0277").is_err());
+        assert!(parse_addr("0100007X:0277").is_err());
+    }
+
+    #[test]
+    fn test_parse_tcp_line() {
+        let line = format!(
+            "   0: {}:0016 {}:0000 0A 00000000:00000000 00:00000000 00000000     0        0 17902 1 0000000000000000 100 0 0 10 0",
+            hex_word([0, 0, 0, 0]),
+            hex_word([0, 0, 0, 0]),
+        );
+        let connection = parse_line(&line, ConnectionProtocol::Tcp).unwrap();
+
+        assert_eq!(connection.protocol, ConnectionProtocol::Tcp);
+        assert_eq!(connection.local.port(), 22);
+        assert_eq!(connection.remote.port(), 0);
+        assert_eq!(connection.state, Some(TcpState::Listen));
+        assert_eq!(connection.inode, Some(17902));
+        assert_eq!(connection.pid, None);
+    }
+
+    #[test]
+    fn test_parse_udp_line() {
+        let line = format!(
+            "  283: {}:0044 {}:0000 07 00000000:00000000 00:00000000 00000000     0        0 20364 2 0000000000000000 0",
+            hex_word([192, 168, 1, 10]),
+            hex_word([0, 0, 0, 0]),
+        );
+        let connection = parse_line(&line, ConnectionProtocol::Udp).unwrap();
+
+        assert_eq!(
+            connection.local,
+            SocketAddr::new(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10)), 68)
+        );
+        assert_eq!(connection.state, None);
+    }
+
+    #[test]
+    fn test_tcp_state() {
+        assert_eq!(tcp_state(0x01), Some(TcpState::Established));
+        assert_eq!(tcp_state(0x0C), Some(TcpState::SynReceived));
+        assert_eq!(tcp_state(0xFF), None);
+    }
+
+    #[test]
+    fn test_socket_inode() {
+        assert_eq!(socket_inode(Path::new("socket:[17902]")), Some(17902));
+        assert_eq!(socket_inode(Path::new("pipe:[17902]")), None);
+        assert_eq!(socket_inode(Path::new("/dev/null")), None);
+    }
+}
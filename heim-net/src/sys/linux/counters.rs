@@ -1,3 +1,4 @@
+use std::io;
 use std::path::Path;
 use std::str::FromStr;
 
@@ -7,7 +8,7 @@ use heim_common::utils::iter::*;
 use heim_common::Pid;
 use heim_runtime as rt;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct IoCounters {
     interface: String,
     rx_bytes: Information,
@@ -29,6 +30,30 @@ pub struct IoCounters {
 }
 
 impl IoCounters {
+    pub fn empty(interface: &str) -> IoCounters {
+        let zero = Information::new::<information::byte>(0);
+
+        IoCounters {
+            interface: interface.to_string(),
+            rx_bytes: zero,
+            rx_packets: 0,
+            rx_errs: 0,
+            rx_drop: 0,
+            rx_fifo: 0,
+            rx_frame: 0,
+            rx_compressed: 0,
+            rx_multicast: 0,
+            tx_bytes: zero,
+            tx_packets: 0,
+            tx_errs: 0,
+            tx_drop: 0,
+            tx_fifo: 0,
+            tx_frame: 0,
+            tx_compressed: 0,
+            tx_multicast: 0,
+        }
+    }
+
     pub fn interface(&self) -> &str {
         self.interface.as_str()
     }
@@ -111,6 +136,64 @@ impl FromStr for IoCounters {
     }
 }
 
+impl IoCounters {
+    // Replaces counters with the values from the `/sys/class/net/{interface}/statistics/`
+    // files, if they are available.
+    //
+    // Unlike `/proc/net/dev` they are always 64-bit wide.
+    async fn with_sysfs_statistics(mut self) -> Result<IoCounters> {
+        let root = rt::linux::sysfs_root()
+            .join("class/net")
+            .join(&self.interface)
+            .join("statistics");
+        if !rt::fs::path_exists(root.clone()).await {
+            return Ok(self);
+        }
+
+        let read = |name: &'static str| {
+            let path = root.join(name);
+            async move {
+                match rt::fs::read_first_line(path.clone()).await {
+                    Ok(line) => line
+                        .trim()
+                        .parse::<u64>()
+                        .map(Some)
+                        .map_err(|e| Error::from(e).with_file(path)),
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+                    Err(e) => Err(Error::from(e).with_file(path)),
+                }
+            }
+        };
+
+        if let Some(value) = read("rx_bytes").await? {
+            self.rx_bytes = Information::new::<information::byte>(value);
+        }
+        if let Some(value) = read("tx_bytes").await? {
+            self.tx_bytes = Information::new::<information::byte>(value);
+        }
+        if let Some(value) = read("rx_packets").await? {
+            self.rx_packets = value;
+        }
+        if let Some(value) = read("tx_packets").await? {
+            self.tx_packets = value;
+        }
+        if let Some(value) = read("rx_errors").await? {
+            self.rx_errs = value;
+        }
+        if let Some(value) = read("tx_errors").await? {
+            self.tx_errs = value;
+        }
+        if let Some(value) = read("rx_dropped").await? {
+            self.rx_drop = value;
+        }
+        if let Some(value) = read("tx_dropped").await? {
+            self.tx_drop = value;
+        }
+
+        Ok(self)
+    }
+}
+
 async fn inner<T: AsRef<Path> + Send + 'static>(
     path: T,
 ) -> Result<impl Stream<Item = Result<IoCounters>>> {
@@ -127,9 +210,32 @@ async fn inner<T: AsRef<Path> + Send + 'static>(
 }
 
 pub async fn io_counters() -> Result<impl Stream<Item = Result<IoCounters>>> {
+    rt::linux::ensure_procfs().await?;
+
     inner(rt::linux::procfs_root().join("net/dev")).await
 }
 
+pub async fn io_counters_physical() -> Result<impl Stream<Item = Result<IoCounters>>> {
+    let stream = io_counters().await?;
+
+    // Only the hardware interfaces are linked with the underlying device,
+    // `lo`, `veth*`, `docker0` and other virtual ones are not
+    Ok(stream.try_filter(|counter| {
+        let path = rt::linux::sysfs_root()
+            .join("class/net")
+            .join(counter.interface())
+            .join("device");
+
+        rt::fs::path_exists(path)
+    }))
+}
+
+pub async fn io_counters_wide() -> Result<impl Stream<Item = Result<IoCounters>>> {
+    let stream = io_counters().await?;
+
+    Ok(stream.and_then(IoCounters::with_sysfs_statistics))
+}
+
 pub async fn io_counters_for_pid(pid: Pid) -> Result<impl Stream<Item = Result<IoCounters>>> {
     let path = rt::linux::procfs_root()
         .join(pid.to_string())
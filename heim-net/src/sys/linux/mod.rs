@@ -1,3 +1,7 @@
+mod connections;
 mod counters;
+mod protocol;
 
+pub use self::connections::*;
 pub use self::counters::*;
+pub use self::protocol::*;
@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+use std::io;
+
+use heim_common::prelude::*;
+use heim_runtime as rt;
+
+use crate::{IcmpCounters, IpCounters, ProtocolCounters, TcpCounters, UdpCounters};
+
+type Table = HashMap<String, u64>;
+
+// Both `/proc/net/snmp` and `/proc/net/netstat` files are consisting of the line pairs,
+// where the first line contains field names and the second one contains values:
+//
+// ```text
+// Udp: InDatagrams NoPorts InErrors
+// Udp: 20 0 0
+// ```
+fn parse_tables(contents: &str) -> Result<HashMap<String, Table>> {
+    let mut tables = HashMap::new();
+    let mut lines = contents.lines();
+
+    while let (Some(header), Some(values)) = (lines.next(), lines.next()) {
+        let mut keys = header.split_whitespace();
+        let mut values = values.split_whitespace();
+        let name = match (keys.next(), values.next()) {
+            (Some(name), Some(name_again)) if name == name_again => name,
+            _ => {
+                let inner = io::Error::from(io::ErrorKind::InvalidData);
+                return Err(Error::from(inner)
+                    .with_message(format!("Malformed protocol counters line {:?}", header)));
+            }
+        };
+
+        let mut table = Table::new();
+        for (key, value) in keys.zip(values) {
+            // Some fields are not counters and might be negative (ex. `Tcp: MaxConn`)
+            if value.starts_with('-') {
+                continue;
+            }
+            let _ = table.insert(key.to_string(), value.parse::<u64>()?);
+        }
+
+        let _ = tables.insert(name.trim_end_matches(':').to_string(), table);
+    }
+
+    Ok(tables)
+}
+
+struct Tables {
+    tables: HashMap<String, Table>,
+    source: &'static str,
+}
+
+impl Tables {
+    fn get(&self, table: &'static str, key: &'static str) -> Result<u64> {
+        self.tables
+            .get(table)
+            .ok_or_else(|| Error::missing_key(table, self.source))?
+            .get(key)
+            .copied()
+            .ok_or_else(|| Error::missing_key(key, self.source))
+    }
+
+    fn get_opt(&self, table: &str, key: &str) -> Option<u64> {
+        self.tables.get(table)?.get(key).copied()
+    }
+}
+
+fn parse(snmp: &str, netstat: Option<&str>) -> Result<ProtocolCounters> {
+    let snmp = Tables {
+        tables: parse_tables(snmp)?,
+        source: "/proc/net/snmp",
+    };
+    let netstat = match netstat {
+        Some(contents) => parse_tables(contents)?,
+        None => HashMap::new(),
+    };
+    let netstat = Tables {
+        tables: netstat,
+        source: "/proc/net/netstat",
+    };
+
+    Ok(ProtocolCounters {
+        ip: IpCounters {
+            in_receives: snmp.get("Ip", "InReceives")?,
+            in_hdr_errors: snmp.get("Ip", "InHdrErrors")?,
+            in_addr_errors: snmp.get("Ip", "InAddrErrors")?,
+            forw_datagrams: snmp.get("Ip", "ForwDatagrams")?,
+            in_discards: snmp.get("Ip", "InDiscards")?,
+            in_delivers: snmp.get("Ip", "InDelivers")?,
+            out_requests: snmp.get("Ip", "OutRequests")?,
+            out_discards: snmp.get("Ip", "OutDiscards")?,
+            out_no_routes: snmp.get("Ip", "OutNoRoutes")?,
+        },
+        icmp: IcmpCounters {
+            in_msgs: snmp.get("Icmp", "InMsgs")?,
+            in_errors: snmp.get("Icmp", "InErrors")?,
+            in_dest_unreachs: snmp.get("Icmp", "InDestUnreachs")?,
+            out_msgs: snmp.get("Icmp", "OutMsgs")?,
+            out_errors: snmp.get("Icmp", "OutErrors")?,
+            out_dest_unreachs: snmp.get("Icmp", "OutDestUnreachs")?,
+        },
+        tcp: TcpCounters {
+            active_opens: snmp.get("Tcp", "ActiveOpens")?,
+            passive_opens: snmp.get("Tcp", "PassiveOpens")?,
+            attempt_fails: snmp.get("Tcp", "AttemptFails")?,
+            estab_resets: snmp.get("Tcp", "EstabResets")?,
+            curr_estab: snmp.get("Tcp", "CurrEstab")?,
+            in_segs: snmp.get("Tcp", "InSegs")?,
+            out_segs: snmp.get("Tcp", "OutSegs")?,
+            retrans_segs: snmp.get("Tcp", "RetransSegs")?,
+            in_errs: snmp.get("Tcp", "InErrs")?,
+            out_rsts: snmp.get("Tcp", "OutRsts")?,
+            listen_overflows: netstat.get_opt("TcpExt", "ListenOverflows"),
+            listen_drops: netstat.get_opt("TcpExt", "ListenDrops"),
+            timeouts: netstat.get_opt("TcpExt", "TCPTimeouts"),
+        },
+        udp: UdpCounters {
+            in_datagrams: snmp.get("Udp", "InDatagrams")?,
+            no_ports: snmp.get("Udp", "NoPorts")?,
+            in_errors: snmp.get("Udp", "InErrors")?,
+            out_datagrams: snmp.get("Udp", "OutDatagrams")?,
+            rcvbuf_errors: snmp.get("Udp", "RcvbufErrors")?,
+            sndbuf_errors: snmp.get("Udp", "SndbufErrors")?,
+        },
+    })
+}
+
+pub async fn protocol_counters() -> Result<ProtocolCounters> {
+    rt::linux::ensure_procfs().await?;
+
+    let snmp_path = rt::linux::procfs_root().join("net/snmp");
+    let snmp = rt::fs::read_to_string(&snmp_path)
+        .await
+        .map_err(|e| Error::from(e).with_file(&snmp_path))?;
+    // Extra counters are optional, as `/proc/net/netstat` might be missing
+    let netstat = rt::fs::read_to_string(rt::linux::procfs_root().join("net/netstat"))
+        .await
+        .ok();
+
+    parse(&snmp, netstat.as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+
+    const SNMP: &str = "\
+Ip: Forwarding DefaultTTL InReceives InHdrErrors InAddrErrors ForwDatagrams InUnknownProtos InDiscards InDelivers OutRequests OutDiscards OutNoRoutes ReasmTimeout ReasmReqds ReasmOKs ReasmFails FragOKs FragFails FragCreates
+Ip: 2 64 10119 1 2 3 0 4 10119 8855 5 6 0 0 0 0 0 0 0
+Icmp: InMsgs InErrors InCsumErrors InDestUnreachs InTimeExcds InParmProbs InSrcQuenchs InRedirects InEchos InEchoReps InTimestamps InTimestampReps InAddrMasks InAddrMaskReps OutMsgs OutErrors OutDestUnreachs OutTimeExcds OutParmProbs OutSrcQuenchs OutRedirects OutEchos OutEchoReps OutTimestamps OutTimestampReps OutAddrMasks OutAddrMaskReps
+Icmp: 7 1 0 6 0 0 0 0 0 0 0 0 0 0 8 2 5 0 0 0 0 0 0 0 0 0 0
+IcmpMsg: InType3 OutType3
+IcmpMsg: 6 5
+Tcp: RtoAlgorithm RtoMin RtoMax MaxConn ActiveOpens PassiveOpens AttemptFails EstabResets CurrEstab InSegs OutSegs RetransSegs InErrs OutRsts InCsumErrors
+Tcp: 1 200 120000 -1 13 4 0 9 2 10099 8842 1 0 11 0
+Udp: InDatagrams NoPorts InErrors OutDatagrams RcvbufErrors SndbufErrors InCsumErrors IgnoredMulti
+Udp: 20 3 2 20 1 0 0 0
+";
+
+    const NETSTAT: &str = "\
+TcpExt: SyncookiesSent ListenOverflows ListenDrops TCPTimeouts
+TcpExt: 0 4 5 6
+IpExt: InNoRoutes InTruncatedPkts
+IpExt: 0 0
+";
+
+    #[test]
+    fn test_parse() {
+        let counters = parse(SNMP, Some(NETSTAT)).unwrap();
+
+        assert_eq!(counters.ip().in_receives(), 10119);
+        assert_eq!(counters.ip().in_hdr_errors(), 1);
+        assert_eq!(counters.ip().out_no_routes(), 6);
+        assert_eq!(counters.icmp().in_msgs(), 7);
+        assert_eq!(counters.icmp().out_dest_unreachs(), 5);
+        assert_eq!(counters.tcp().active_opens(), 13);
+        assert_eq!(counters.tcp().retrans_segs(), 1);
+        assert_eq!(counters.tcp().out_rsts(), 11);
+        assert_eq!(counters.tcp().listen_overflows(), Some(4));
+        assert_eq!(counters.tcp().timeouts(), Some(6));
+        assert_eq!(counters.udp().no_ports(), 3);
+        assert_eq!(counters.udp().in_errors(), 2);
+        assert_eq!(counters.udp().rcvbuf_errors(), 1);
+    }
+
+    #[test]
+    fn test_parse_without_netstat() {
+        let counters = parse(SNMP, None).unwrap();
+
+        assert_eq!(counters.tcp().curr_estab(), 2);
+        assert_eq!(counters.tcp().listen_drops(), None);
+    }
+
+    #[test]
+    fn test_parse_missing_table() {
+        let snmp = SNMP.replace("Udp:", "Foo:");
+
+        assert!(parse(&snmp, None).is_err());
+    }
+}
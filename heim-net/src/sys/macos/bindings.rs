@@ -66,6 +66,7 @@ impl Iterator for Routes {
     }
 }
 
+#[derive(Clone, Copy)]
 #[repr(C)]
 pub struct if_data64 {
     pub ifi_type: libc::c_uchar,
@@ -95,6 +96,7 @@ pub struct if_data64 {
     pub ifi_lastchange: libc::timeval,
 }
 
+#[derive(Clone, Copy)]
 #[repr(C)]
 pub struct if_msghdr2 {
     pub ifm_msglen: libc::c_ushort,
@@ -1,16 +1,26 @@
 use std::fmt;
+use std::mem;
 
 use heim_common::prelude::*;
 use heim_common::units::{information, Information};
 
 use super::bindings::{if_msghdr2, net_pf_route};
 
+#[derive(Clone)]
 pub struct IoCounters {
     name: String,
     data: if_msghdr2,
 }
 
 impl IoCounters {
+    pub fn empty(interface: &str) -> IoCounters {
+        IoCounters {
+            name: interface.to_string(),
+            // All fields are plain integers, so zeroed struct is valid
+            data: unsafe { mem::zeroed() },
+        }
+    }
+
     pub fn interface(&self) -> &str {
         self.name.as_str()
     }
@@ -71,3 +81,11 @@ pub async fn io_counters() -> Result<impl Stream<Item = Result<IoCounters>>> {
 
     Ok(stream::iter(interfaces))
 }
+
+pub async fn io_counters_physical() -> Result<impl Stream<Item = Result<IoCounters>>> {
+    let stream = io_counters().await?;
+
+    Ok(stream.try_filter(|counter| {
+        future::ready(!crate::sys::is_virtual_interface(counter.interface()))
+    }))
+}
@@ -1,4 +1,8 @@
 mod bindings;
+mod connections;
 mod counters;
+mod protocol;
 
+pub use self::connections::*;
 pub use self::counters::*;
+pub use self::protocol::*;
@@ -0,0 +1,9 @@
+use heim_common::not_supported;
+use heim_common::prelude::*;
+
+use crate::ProtocolCounters;
+
+pub async fn protocol_counters() -> Result<ProtocolCounters> {
+    // TODO: Counters can be fetched via the `net.inet.*.stats` sysctls
+    Err(not_supported("protocol counters"))
+}
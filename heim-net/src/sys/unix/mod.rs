@@ -1,3 +1,18 @@
 mod nic;
 
 pub use self::nic::*;
+
+// Loopback, tunnels, bridges and other software-defined interfaces prefixes
+#[cfg(not(target_os = "linux"))]
+const VIRTUAL_INTERFACES: &[&str] = &[
+    "lo", "gif", "stf", "utun", "bridge", "awdl", "llw", "tap", "tun", "vmnet", "pflog", "pfsync",
+    "enc",
+];
+
+/// Checks if network interface is a virtual one by its name.
+#[cfg(not(target_os = "linux"))]
+pub fn is_virtual_interface(name: &str) -> bool {
+    VIRTUAL_INTERFACES
+        .iter()
+        .any(|prefix| name.starts_with(prefix))
+}
@@ -1,6 +1,7 @@
+use std::collections::HashMap;
 use std::net::SocketAddr;
 
-use macaddr::MacAddr;
+use macaddr::{MacAddr, MacAddr6};
 use nix::ifaddrs;
 use nix::net::if_::if_nametoindex;
 use nix::net::if_::InterfaceFlags;
@@ -11,11 +12,15 @@ use heim_common::prelude::*;
 use crate::Address;
 
 #[derive(Debug)]
-pub struct Nic(ifaddrs::InterfaceAddress);
+pub struct Nic {
+    inner: ifaddrs::InterfaceAddress,
+    address: Address,
+    mac_address: Option<MacAddr6>,
+}
 
 impl Nic {
     pub fn name(&self) -> &str {
-        self.0.interface_name.as_str()
+        self.inner.interface_name.as_str()
     }
 
     pub fn index(&self) -> Option<u32> {
@@ -23,75 +28,131 @@ impl Nic {
     }
 
     pub fn address(&self) -> Address {
-        self.0
-            .address
-            .as_ref()
-            .expect("NIC stream should exclude entries without address")
-            .into()
+        self.address
+    }
+
+    pub fn mac_address(&self) -> Option<MacAddr6> {
+        self.mac_address
     }
 
     pub fn netmask(&self) -> Option<Address> {
-        self.0.netmask.as_ref().map(Into::into)
+        self.inner.netmask.as_ref().and_then(to_address)
     }
 
     pub fn broadcast(&self) -> Option<Address> {
-        self.0.broadcast.as_ref().map(Into::into)
+        self.inner.broadcast.as_ref().and_then(to_address)
     }
 
     pub fn destination(&self) -> Option<Address> {
-        self.0.destination.as_ref().map(Into::into)
+        self.inner.destination.as_ref().and_then(to_address)
     }
 
     pub fn is_up(&self) -> bool {
-        self.0.flags.contains(InterfaceFlags::IFF_UP)
+        self.inner.flags.contains(InterfaceFlags::IFF_UP)
     }
 
     pub fn is_running(&self) -> bool {
-        self.0.flags.contains(InterfaceFlags::IFF_RUNNING)
+        self.inner.flags.contains(InterfaceFlags::IFF_RUNNING)
     }
 
     pub fn is_broadcast(&self) -> bool {
-        self.0.flags.contains(InterfaceFlags::IFF_BROADCAST)
+        self.inner.flags.contains(InterfaceFlags::IFF_BROADCAST)
     }
 
     pub fn is_loopback(&self) -> bool {
-        self.0.flags.contains(InterfaceFlags::IFF_LOOPBACK)
+        self.inner.flags.contains(InterfaceFlags::IFF_LOOPBACK)
     }
 
     pub fn is_point_to_point(&self) -> bool {
-        self.0.flags.contains(InterfaceFlags::IFF_POINTOPOINT)
+        self.inner.flags.contains(InterfaceFlags::IFF_POINTOPOINT)
     }
 
     pub fn is_multicast(&self) -> bool {
-        self.0.flags.contains(InterfaceFlags::IFF_MULTICAST)
+        self.inner.flags.contains(InterfaceFlags::IFF_MULTICAST)
+    }
+
+    pub fn is_promiscuous(&self) -> bool {
+        self.inner.flags.contains(InterfaceFlags::IFF_PROMISC)
     }
 }
 
 pub async fn nic() -> Result<impl Stream<Item = Result<Nic>> + Send + Sync> {
-    let iter = ifaddrs::getifaddrs()?
-        .filter_map(|addr| {
-            if addr.address.is_some() {
-                Some(Ok(Nic(addr)))
-            } else {
-                None
-            }
+    let entries = ifaddrs::getifaddrs()?
+        .filter_map(|entry| {
+            // Entries without address or with an unsupported address family
+            // (ex. `AF_NETLINK`) are not interesting for the NIC stream
+            let address = entry.address.as_ref().and_then(to_address)?;
+            Some((entry, address))
+        })
+        .collect::<Vec<_>>();
+
+    // Link-level entries are sharing the interface name with the Internet ones,
+    // so the hardware address can be attached to all addresses of the same interface
+    let mac_addresses = entries
+        .iter()
+        .filter_map(|(entry, address)| match address {
+            Address::Link(MacAddr::V6(mac)) => Some((entry.interface_name.clone(), *mac)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    let iter = entries
+        .into_iter()
+        .map(|(inner, address)| {
+            let mac_address = mac_addresses.get(&inner.interface_name).copied();
+            Ok(Nic {
+                inner,
+                address,
+                mac_address,
+            })
         })
         .collect::<Vec<_>>();
 
     Ok(stream::iter(iter))
 }
 
-impl From<&socket::SockAddr> for Address {
-    fn from(s: &socket::SockAddr) -> Self {
-        use nix::sys::socket::SockAddr::*;
-
-        match *s {
-            Inet(addr) => match addr.to_std() {
-                SocketAddr::V4(addr) => Address::Inet(addr),
-                SocketAddr::V6(addr) => Address::Inet6(addr),
-            },
-            Link(addr) => Address::Link(MacAddr::from(addr.addr())),
-            other => unimplemented!("Unknown sockaddr: {:?}", other),
-        }
+fn to_address(s: &socket::SockAddr) -> Option<Address> {
+    use nix::sys::socket::SockAddr::*;
+
+    match *s {
+        Inet(addr) => match addr.to_std() {
+            SocketAddr::V4(addr) => Some(Address::Inet(addr)),
+            SocketAddr::V6(addr) => Some(Address::Inet6(addr)),
+        },
+        Link(addr) => Some(Address::Link(MacAddr::from(addr.addr()))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv6Addr, SocketAddr, SocketAddrV6};
+
+    use nix::sys::socket::{InetAddr, SockAddr};
+
+    use super::to_address;
+    use crate::Address;
+
+    fn address(ip: Ipv6Addr, scope_id: u32) -> Address {
+        let addr = SocketAddr::V6(SocketAddrV6::new(ip, 0, 0, scope_id));
+        let sock_addr = SockAddr::new_inet(InetAddr::from_std(&addr));
+
+        to_address(&sock_addr).unwrap()
+    }
+
+    #[test]
+    fn test_link_local_scope_id() {
+        let link_local = address("fe80::1".parse().unwrap(), 2);
+        assert_eq!(link_local.scope_id(), Some(2));
+
+        let loopback = address(Ipv6Addr::LOCALHOST, 0);
+        assert_eq!(loopback.scope_id(), None);
+    }
+
+    #[test]
+    fn test_unsupported_address() {
+        let sock_addr = SockAddr::new_unix("/tmp/heim.sock").unwrap();
+
+        assert_eq!(to_address(&sock_addr), None);
     }
 }
@@ -0,0 +1,8 @@
+use heim_common::not_supported;
+use heim_common::prelude::*;
+
+use crate::Connection;
+
+pub async fn connections() -> Result<Vec<Connection>> {
+    Err(not_supported("connections"))
+}
@@ -1,47 +1,61 @@
 use heim_common::prelude::*;
-use heim_common::units::Information;
+use heim_common::units::{information, Information};
 
-#[derive(Debug)]
-pub struct IoCounters;
+// TODO: Real counters are not implemented yet,
+// see https://github.com/heim-rs/heim/issues/26
+#[derive(Debug, Clone)]
+pub struct IoCounters {
+    interface: String,
+}
 
 impl IoCounters {
+    pub fn empty(interface: &str) -> IoCounters {
+        IoCounters {
+            interface: interface.to_string(),
+        }
+    }
+
     pub fn interface(&self) -> &str {
-        unimplemented!()
+        self.interface.as_str()
     }
 
     pub fn bytes_sent(&self) -> Information {
-        unimplemented!()
+        Information::new::<information::byte>(0)
     }
 
     pub fn bytes_recv(&self) -> Information {
-        unimplemented!()
+        Information::new::<information::byte>(0)
     }
 
     pub fn packets_sent(&self) -> u64 {
-        unimplemented!()
+        0
     }
 
     pub fn packets_recv(&self) -> u64 {
-        unimplemented!()
+        0
     }
 
     pub fn errors_sent(&self) -> u64 {
-        unimplemented!()
+        0
     }
 
     pub fn errors_recv(&self) -> u64 {
-        unimplemented!()
+        0
     }
 
     pub fn drop_recv(&self) -> u64 {
-        unimplemented!()
+        0
     }
 
     pub fn drop_sent(&self) -> u64 {
-        unimplemented!()
+        0
     }
 }
 
 pub async fn io_counters() -> Result<impl Stream<Item = Result<IoCounters>>> {
     Ok(stream::iter(vec![]))
 }
+
+pub async fn io_counters_physical() -> Result<impl Stream<Item = Result<IoCounters>>> {
+    io_counters().await
+}
@@ -1,5 +1,9 @@
+mod connections;
 mod counters;
 mod nic;
+mod protocol;
 
+pub use self::connections::*;
 pub use self::counters::*;
 pub use self::nic::*;
+pub use self::protocol::*;
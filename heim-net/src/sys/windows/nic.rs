@@ -8,6 +8,8 @@ use std::net::SocketAddrV6;
 use std::ffi::CStr;
 use widestring::UCStr;
 
+use macaddr::MacAddr6;
+
 use winapi::shared::ifdef::IfOperStatusUp;
 use winapi::shared::minwindef::ULONG;
 use winapi::shared::ntdef::NULL;
@@ -30,6 +32,7 @@ pub struct Nic {
     guid: String,
     friendly_name: String,
     is_up: bool,
+    mac_address: Option<MacAddr6>,
     address: Option<Address>,
     netmask: Option<Address>,
 }
@@ -114,6 +117,10 @@ impl Nic {
         &self.guid
     }
 
+    pub fn mac_address(&self) -> Option<MacAddr6> {
+        self.mac_address
+    }
+
     pub fn address(&self) -> Address {
         self.address
             .unwrap_or_else(|| Address::Inet(SocketAddrV4::new(Ipv4Addr::new(0, 0, 0, 0), 0)))
@@ -152,6 +159,12 @@ impl Nic {
             _ => false,
         }
     }
+
+    pub fn is_promiscuous(&self) -> bool {
+        // TODO: `GetAdaptersAddresses` does not provide this information,
+        // it should be fetched via the `OID_GEN_CURRENT_PACKET_FILTER` NDIS query.
+        false
+    }
 }
 
 pub async fn nic() -> Result<impl Stream<Item = Result<Nic>> + Send + Sync> {
@@ -206,6 +219,7 @@ pub async fn nic() -> Result<impl Stream<Item = Result<Nic>> + Send + Sync> {
         let iface_guid_cstr;
         let iface_fname_ucstr;
         let is_up;
+        let mac_address;
         let mut cur_address;
 
         unsafe {
@@ -215,6 +229,17 @@ pub async fn nic() -> Result<impl Stream<Item = Result<Nic>> + Send + Sync> {
             cur_address = *(cur_iface.FirstUnicastAddress);
             is_up = cur_iface.OperStatus == IfOperStatusUp;
         }
+        // Physical address is empty for the software interfaces (ex. loopback)
+        // and it might be 8 bytes long for some exotic ones (ex. FireWire)
+        mac_address = match cur_iface.PhysicalAddressLength {
+            6 => {
+                let raw = &cur_iface.PhysicalAddress;
+                Some(MacAddr6::new(
+                    raw[0], raw[1], raw[2], raw[3], raw[4], raw[5],
+                ))
+            }
+            _ => None,
+        };
         let iface_guid = iface_guid_cstr
             .to_str()
             .map(|s| s.to_string())
@@ -226,6 +251,7 @@ pub async fn nic() -> Result<impl Stream<Item = Result<Nic>> + Send + Sync> {
             friendly_name: iface_friendly_name,
             guid: iface_guid,
             is_up,
+            mac_address,
             address: None,
             netmask: None,
         };
@@ -1,4 +1,5 @@
 use heim_common::prelude::*;
+use heim_common::units::{time, Time};
 use heim_net as net;
 
 #[cfg(target_os = "linux")]
@@ -23,6 +24,10 @@ async fn smoke_io_counters() -> Result<()> {
         let _ = counter.errors_recv();
         let _ = counter.drop_recv();
 
+        let rates = counter.rate_since(&counter, Time::new::<time::second>(1.0));
+        assert_eq!(rates.packets_sent(), 0.0);
+        assert_eq!(rates.packets_recv(), 0.0);
+
         #[cfg(any(target_os = "linux", target_os = "windows"))]
         let _ = counter.drop_sent();
     }
@@ -30,6 +35,48 @@ async fn smoke_io_counters() -> Result<()> {
     Ok(())
 }
 
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_protocol_counters() -> Result<()> {
+    let counters = net::protocol_counters().await?;
+
+    let _ = counters.ip().in_receives();
+    let _ = counters.tcp().retrans_segs();
+    let _ = counters.tcp().listen_overflows();
+    let _ = counters.udp().in_errors();
+    let _ = counters.icmp().in_errors();
+
+    Ok(())
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_io_counters_wide() -> Result<()> {
+    let counters = net::os::linux::io_counters_wide().await?;
+    ::futures::pin_mut!(counters);
+    while let Some(counter) = counters.next().await {
+        let counter = counter?;
+
+        let _ = counter.interface();
+        let _ = counter.bytes_sent();
+        let _ = counter.bytes_recv();
+        let _ = counter.drop_sent();
+    }
+
+    Ok(())
+}
+
+#[heim_derive::test]
+async fn smoke_io_counters_physical() -> Result<()> {
+    let counters = net::io_counters_physical().await?;
+    ::futures::pin_mut!(counters);
+    while let Some(counter) = counters.next().await {
+        assert_ne!(counter?.interface(), "lo");
+    }
+
+    Ok(())
+}
+
 #[heim_derive::test]
 async fn smoke_nic() -> Result<()> {
     let nic = net::nic().await?;
@@ -40,7 +87,12 @@ async fn smoke_nic() -> Result<()> {
         println!("Interface");
         println!("  name: {:?}", iface.name());
         println!("  index: {:?}", iface.index());
+        println!(
+            "  mac_address: {:?}",
+            iface.mac_address().map(|mac| mac.to_string())
+        );
         println!("  address: {:?}", iface.address());
+        println!("  scope_id: {:?}", iface.scope_id());
         println!("  netmask: {:?}", iface.netmask());
 
         println!("  destination: {:?}", iface.destination());
@@ -48,6 +100,11 @@ async fn smoke_nic() -> Result<()> {
         println!("  is_running: {:?}", iface.is_running());
         println!("  is_loopback: {:?}", iface.is_loopback());
         println!("  is_multicast: {:?}", iface.is_multicast());
+        println!("  is_promiscuous: {:?}", iface.is_promiscuous());
+
+        if let net::Address::Link(net::MacAddr::V6(mac)) = iface.address() {
+            assert_eq!(iface.mac_address(), Some(mac));
+        }
 
         #[cfg(target_os = "windows")]
         {
@@ -77,3 +134,36 @@ async fn smoke_nic() -> Result<()> {
 
     Ok(())
 }
+
+#[heim_derive::test]
+async fn smoke_nics_with_counters() -> Result<()> {
+    let nics = net::nics_with_counters().await?;
+    ::futures::pin_mut!(nics);
+    while let Some(pair) = nics.next().await {
+        let (nic, counters) = pair?;
+
+        assert_eq!(nic.name(), counters.interface());
+        let _ = counters.bytes_sent();
+        let _ = counters.bytes_recv();
+    }
+
+    Ok(())
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_connections() -> Result<()> {
+    let connections = net::connections().await?;
+    ::futures::pin_mut!(connections);
+    while let Some(connection) = connections.next().await {
+        let connection = connection?;
+
+        let _ = connection.local();
+        let _ = connection.remote();
+        let _ = connection.inode();
+        let _ = connection.pid();
+        assert_eq!(connection.state().is_some(), connection.protocol().is_tcp());
+    }
+
+    Ok(())
+}
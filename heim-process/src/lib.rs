@@ -40,3 +40,18 @@ pub use heim_common::Pid;
 
 #[cfg(target_os = "linux")]
 pub use heim_net::IoCounters;
+
+/// Re-exports of the commonly used process types and the measurement units for them.
+///
+/// ```rust
+/// use heim_process::prelude::*;
+/// ```
+pub mod prelude {
+    pub use crate::{
+        Command, CpuTime, CpuUsage, Environment, Memory, Pid, Process, ProcessError, ProcessResult,
+        Status,
+    };
+    // Crate root `IoCounters` is shadowed by the `heim_net::IoCounters` on Linux
+    pub use crate::process::IoCounters;
+    pub use heim_common::units::{information, ratio, time, Information, Ratio, Time};
+}
@@ -63,6 +63,11 @@ impl Process {
 
     /// Returns process command line.
     ///
+    /// ## Compatibility
+    ///
+    /// For Windows this method is not implemented yet and it fails with an error
+    /// for which [`Error::is_not_supported`] returns `true`.
+    ///
     /// ## Example
     ///
     /// ```rust
@@ -71,15 +76,20 @@ impl Process {
     /// # #[heim_derive::main]
     /// # async fn main() -> ProcessResult<()> {
     /// let process = process::current().await?;
+    /// # #[cfg(not(windows))]
+    /// # {
     /// let command = process.command().await?;
     /// println!("Command line arguments:");
     /// for arg in &command {
     ///     println!("{:?}", arg);
     /// }
+    /// # }
     ///
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// [`Error::is_not_supported`]: ../heim_common/struct.Error.html#method.is_not_supported
     pub async fn command(&self) -> ProcessResult<Command> {
         self.as_ref().command().await.map(Into::into)
     }
@@ -101,6 +111,13 @@ impl Process {
     }
 
     /// Returns process environment.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Windows this method is not implemented yet and it fails with an error
+    /// for which [`Error::is_not_supported`] returns `true`.
+    ///
+    /// [`Error::is_not_supported`]: ../heim_common/struct.Error.html#method.is_not_supported
     pub async fn environment(&self) -> ProcessResult<Environment> {
         self.as_ref().environment().await.map(Into::into)
     }
@@ -241,6 +258,13 @@ impl Process {
     /// ## Returns
     ///
     /// If the process is already terminated, this method returns `Ok(())`.
+    ///
+    /// ## Compatibility
+    ///
+    /// For Windows this method is not implemented yet and it fails with an error
+    /// for which [`Error::is_not_supported`] returns `true`.
+    ///
+    /// [`Error::is_not_supported`]: ../heim_common/struct.Error.html#method.is_not_supported
     pub async fn wait(&self) -> ProcessResult<()> {
         self.as_ref().wait().await
     }
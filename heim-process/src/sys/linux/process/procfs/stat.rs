@@ -2,7 +2,7 @@ use std::io;
 use std::str::FromStr;
 
 use heim_common::prelude::*;
-use heim_common::sys::unix::CLOCK_TICKS;
+use heim_common::sys::unix::clock_ticks_cached;
 use heim_common::units::{time, Time};
 use heim_common::utils::iter::{ParseIterator, TryIterator};
 use heim_runtime as rt;
@@ -96,7 +96,7 @@ impl FromStr for Stat {
         // otherwise we will lose the fraction part, leading to the same CPU time values
         // if called consequently. That breaks `top` example (and similar things),
         // so these fractions are really important here.
-        let ticks = *CLOCK_TICKS as f64;
+        let ticks = clock_ticks_cached()?;
 
         // TODO: Potential precision loss during the `as f64` cast
         let start_time = start_time as f64 / ticks;
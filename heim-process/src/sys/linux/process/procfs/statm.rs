@@ -1,6 +1,7 @@
 use std::str::FromStr;
 
 use heim_common::prelude::*;
+use heim_common::sys::unix;
 use heim_common::units::{information, Information};
 use heim_common::utils::iter::ParseIterator;
 use heim_runtime as rt;
@@ -8,15 +9,6 @@ use heim_runtime as rt;
 use crate::sys::linux::process::procfs::process_file_path;
 use crate::{Pid, ProcessResult};
 
-// TODO: Join with `heim-memory/src/sys/macos/mod.rs:PAGE_SIZE`
-lazy_static::lazy_static! {
-    static ref PAGE_SIZE: u64 = {
-        unsafe {
-            libc::sysconf(libc::_SC_PAGESIZE) as u64
-        }
-    };
-}
-
 #[derive(Debug)]
 pub struct Memory {
     size: Information,
@@ -52,23 +44,24 @@ impl FromStr for Memory {
     type Err = Error;
 
     fn from_str(value: &str) -> Result<Memory> {
+        let page_size = unix::page_size()?;
         let mut parts = value.split_ascii_whitespace();
         let size = parts
             .try_parse_next::<u64, _>()
-            .map(|value| Information::new::<information::byte>(value * *PAGE_SIZE))?;
+            .map(|value| Information::new::<information::byte>(value * page_size))?;
         let resident = parts
             .try_parse_next::<u64, _>()
-            .map(|value| Information::new::<information::byte>(value * *PAGE_SIZE))?;
+            .map(|value| Information::new::<information::byte>(value * page_size))?;
         let shared = parts
             .try_parse_next::<u64, _>()
-            .map(|value| Information::new::<information::byte>(value * *PAGE_SIZE))?;
+            .map(|value| Information::new::<information::byte>(value * page_size))?;
         let text = parts
             .try_parse_next::<u64, _>()
-            .map(|value| Information::new::<information::byte>(value * *PAGE_SIZE))?;
+            .map(|value| Information::new::<information::byte>(value * page_size))?;
         let _lib = parts.next();
         let data = parts
             .try_parse_next::<u64, _>()
-            .map(|value| Information::new::<information::byte>(value * *PAGE_SIZE))?;
+            .map(|value| Information::new::<information::byte>(value * page_size))?;
 
         Ok(Memory {
             size,
@@ -1,5 +1,7 @@
 use std::ffi::{OsStr, OsString};
 
+use heim_common::not_supported;
+
 use crate::{Pid, ProcessResult};
 
 #[derive(Debug)]
@@ -40,6 +42,6 @@ impl<'a> Iterator for CommandIter<'a> {
 }
 
 pub fn command(_pid: Pid) -> ProcessResult<Command> {
-    // TODO: STUB
-    Ok(Command {})
+    // TODO: Command line can be read from the process memory via the PEB
+    Err(not_supported("process command line").into())
 }
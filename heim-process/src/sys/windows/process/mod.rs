@@ -4,6 +4,7 @@ use std::hash;
 use std::os::windows::ffi::OsStringExt;
 use std::path::PathBuf;
 
+use heim_common::not_supported;
 use heim_common::prelude::*;
 use heim_common::units::Time;
 use winapi::um::processthreadsapi;
@@ -119,7 +120,8 @@ impl Process {
     }
 
     pub async fn environment(&self) -> ProcessResult<Environment> {
-        unimplemented!()
+        // TODO: Environment block can be read from the process memory via the PEB
+        Err(not_supported("process environment").into())
     }
 
     pub async fn create_time(&self) -> ProcessResult<Time> {
@@ -188,7 +190,8 @@ impl Process {
     }
 
     pub async fn wait(&self) -> ProcessResult<()> {
-        unimplemented!()
+        // TODO: Process handle can be awaited with `WaitForSingleObject`
+        Err(not_supported("process wait").into())
     }
 
     pub async fn io_counters(&self) -> ProcessResult<IoCounters> {
@@ -2,9 +2,16 @@ use std::error;
 
 use static_assertions::assert_impl_all;
 
+use heim_common::units::Information;
 use heim_process::ProcessError;
 
 #[test]
 fn test_public_api_contract() {
     assert_impl_all!(ProcessError: Send, Sync, error::Error);
 }
+
+// Fails to compile if the prelude re-exports some other `IoCounters` type
+#[allow(dead_code)]
+fn prelude_io_counters(counters: &heim_process::prelude::IoCounters) -> (Information, Information) {
+    (counters.bytes_read(), counters.bytes_written())
+}
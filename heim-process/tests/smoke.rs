@@ -29,13 +29,16 @@ async fn smoke_pids() -> Result<()> {
 /// that queried process will be okay during the testing.
 macro_rules! try_method {
     ($method:expr) => {
-        if let Err(ProcessError::Load(e)) = $method.await {
-            assert!(
+        match $method.await {
+            // Some methods are not implemented for all platforms yet
+            Err(ProcessError::Load(e)) if e.is_not_supported() => {}
+            Err(ProcessError::Load(e)) => assert!(
                 false,
                 "`{}` method returned an error: {:#?}",
                 stringify!($method),
                 e
-            );
+            ),
+            _ => {}
         }
     };
 }
@@ -46,3 +46,11 @@ where
 {
     smol::unblock(f).await
 }
+
+/// Drives the future to completion on the current thread.
+pub fn block_on<F, R>(f: F) -> R
+where
+    F: Future<Output = R>,
+{
+    smol::block_on(f)
+}
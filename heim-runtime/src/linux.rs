@@ -4,6 +4,8 @@
 /// and Sysfs.
 use once_cell::sync::OnceCell;
 use std::borrow::Cow;
+use std::env;
+use std::io;
 use std::path::{Path, PathBuf};
 
 /// Holds global state for custom paths. Static's leveraging this type
@@ -13,10 +15,23 @@ type RootCell = OnceCell<PathBuf>;
 static PROCFS_ROOT: RootCell = OnceCell::new();
 static SYSFS_ROOT: RootCell = OnceCell::new();
 
+/// Environment variable which overrides the default PROCFS_ROOT path.
+pub const PROCFS_ROOT_ENV: &str = "HEIM_PROC_ROOT";
+/// Environment variable which overrides the default SYSFS_ROOT path.
+pub const SYSFS_ROOT_ENV: &str = "HEIM_SYS_ROOT";
+
+fn default_root(env_key: &str, default: &str) -> PathBuf {
+    match env::var_os(env_key) {
+        Some(root) if !root.is_empty() => PathBuf::from(root),
+        _ => PathBuf::from(default),
+    }
+}
+
 /// Instantiates the OnceCell holding PROCFS_ROOT in the case that it hasn't
 /// already been instantiated.
 ///
-/// This can only be set ONCE per binary run.
+/// This can only be set ONCE per binary run and takes precedence
+/// over the `HEIM_PROC_ROOT` environment variable.
 pub fn set_procfs_root<T: Into<Cow<'static, Path>>>(root: T) {
     let root = root.into().into_owned();
     let _ = PROCFS_ROOT.get_or_init(|| root);
@@ -24,23 +39,50 @@ pub fn set_procfs_root<T: Into<Cow<'static, Path>>>(root: T) {
 
 /// Returns the static `Path` value of a configured PROCFS_ROOT.
 ///
-/// If uninitialized, initializes the PROCFS_ROOT with the default path - `/proc`
+/// If uninitialized, initializes the PROCFS_ROOT with the `HEIM_PROC_ROOT`
+/// environment variable value, or with the default path - `/proc`
 pub fn procfs_root() -> &'static Path {
-    PROCFS_ROOT.get_or_init(|| PathBuf::from("/proc")).as_ref()
+    PROCFS_ROOT
+        .get_or_init(|| default_root(PROCFS_ROOT_ENV, "/proc"))
+        .as_ref()
+}
+
+/// Checks if procfs is mounted at the configured PROCFS_ROOT.
+///
+/// Returns `io::ErrorKind::NotFound` error with a descriptive message if it is not,
+/// which is common for the minimal containers and chroots.
+pub async fn ensure_procfs() -> io::Result<()> {
+    let root = procfs_root();
+    // `stat` file is always present for any mounted procfs
+    if crate::fs::path_exists(root.join("stat")).await {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "procfs is not mounted at {}, mount it or configure its location with `set_procfs_root`",
+                root.display()
+            ),
+        ))
+    }
 }
 
 /// Instantiates the OnceCell holding SYSFS_ROOT in the case that it hasn't
 /// already been instantiated.
 ///
-/// This can only be set ONCE per binary run.
+/// This can only be set ONCE per binary run and takes precedence
+/// over the `HEIM_SYS_ROOT` environment variable.
 pub fn set_sysfs_root<T: Into<Cow<'static, Path>>>(root: T) {
     let root = root.into().into_owned();
     let _ = SYSFS_ROOT.get_or_init(|| root);
 }
 
-/// Returns the static `Path` value of a configured PROCFS_ROOT.
+/// Returns the static `Path` value of a configured SYSFS_ROOT.
 ///
-/// If uninitialized, initializes the PROCFS_ROOT with the default path - `/sys`
+/// If uninitialized, initializes the SYSFS_ROOT with the `HEIM_SYS_ROOT`
+/// environment variable value, or with the default path - `/sys`
 pub fn sysfs_root() -> &'static Path {
-    SYSFS_ROOT.get_or_init(|| PathBuf::from("/sys")).as_ref()
+    SYSFS_ROOT
+        .get_or_init(|| default_root(SYSFS_ROOT_ENV, "/sys"))
+        .as_ref()
 }
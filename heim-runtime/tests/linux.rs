@@ -34,3 +34,39 @@ rusty_fork_test! {
         assert_eq!(rt::linux::sysfs_root(), PathBuf::from("/host/sys"));
     }
 }
+
+rusty_fork_test! {
+    #[test]
+    fn test_missing_proc_root() {
+        rt::linux::set_procfs_root(Path::new("/heim/missing/proc"));
+        let err = smol::block_on(rt::linux::ensure_procfs()).unwrap_err();
+
+        assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+        assert!(err.to_string().contains("/heim/missing/proc"));
+    }
+}
+
+rusty_fork_test! {
+    #[test]
+    fn test_env_proc_root() {
+        std::env::set_var(rt::linux::PROCFS_ROOT_ENV, "/chroot/proc");
+        assert_eq!(rt::linux::procfs_root(), PathBuf::from("/chroot/proc"));
+    }
+}
+
+rusty_fork_test! {
+    #[test]
+    fn test_env_sys_root() {
+        std::env::set_var(rt::linux::SYSFS_ROOT_ENV, "/chroot/sys");
+        assert_eq!(rt::linux::sysfs_root(), PathBuf::from("/chroot/sys"));
+    }
+}
+
+rusty_fork_test! {
+    #[test]
+    fn test_explicit_root_overrides_env() {
+        std::env::set_var(rt::linux::PROCFS_ROOT_ENV, "/chroot/proc");
+        rt::linux::set_procfs_root(Path::new("/host/proc"));
+        assert_eq!(rt::linux::procfs_root(), PathBuf::from("/host/proc"));
+    }
+}
@@ -25,6 +25,18 @@
 
 mod sys;
 
+pub mod os;
 mod temperatures;
 
 pub use self::temperatures::*;
+pub use heim_common::Temperature;
+
+/// Re-exports of the commonly used sensors types and the measurement units for them.
+///
+/// ```rust
+/// use heim_sensors::prelude::*;
+/// ```
+pub mod prelude {
+    pub use crate::{Temperature, TemperatureSensor, TemperatureSource};
+    pub use heim_common::units::{thermodynamic_temperature, ThermodynamicTemperature};
+}
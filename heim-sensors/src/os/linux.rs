@@ -0,0 +1,182 @@
+//! Linux-specific extensions.
+
+use heim_common::prelude::*;
+use heim_common::units::{Ratio, ThermodynamicTemperature};
+
+/// Thermal zone trip point type.
+///
+/// See the `thermal/sysfs-api.txt` kernel documentation for details.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum TripPointKind {
+    /// System is shut down when this temperature is reached.
+    Critical,
+    /// System is going to be hibernated when this temperature is reached.
+    Hot,
+    /// Passive cooling (ex. CPU frequency throttling) is started at this temperature.
+    Passive,
+    /// Active cooling (ex. fans) is started at this temperature.
+    Active,
+}
+
+/// Thermal zone trip point.
+#[derive(Debug, Clone)]
+pub struct TripPoint {
+    pub(crate) kind: TripPointKind,
+    pub(crate) temperature: ThermodynamicTemperature,
+}
+
+impl TripPoint {
+    /// Returns trip point type.
+    pub fn kind(&self) -> TripPointKind {
+        self.kind
+    }
+
+    /// Returns temperature at which this trip point is triggered.
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+}
+
+/// Thermal zone, as exposed by `/sys/class/thermal/thermal_zone*`.
+///
+/// See [thermal_zones] function.
+///
+/// [thermal_zones]: ./fn.thermal_zones.html
+#[derive(Debug, Clone)]
+pub struct ThermalZone {
+    pub(crate) name: String,
+    pub(crate) temperature: ThermodynamicTemperature,
+    pub(crate) trip_points: Vec<TripPoint>,
+}
+
+impl ThermalZone {
+    /// Returns thermal zone type, ex. `acpitz`, `x86_pkg_temp` or `nvme`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns current thermal zone temperature.
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    /// Returns thermal zone trip points.
+    pub fn trip_points(&self) -> &[TripPoint] {
+        &self.trip_points
+    }
+}
+
+/// Returns a stream over the [thermal zones].
+///
+/// Zones with temperature which can't be read at the moment
+/// (ex. if the device is suspended) are skipped.
+///
+/// Empty stream is returned if there are no thermal zones available.
+///
+/// [thermal zones]: ./struct.ThermalZone.html
+#[cfg(target_os = "linux")]
+pub fn thermal_zones() -> impl Stream<Item = Result<ThermalZone>> {
+    crate::sys::thermal_zones()
+}
+
+/// Fan speed sensor, as exposed by the `/sys/class/hwmon/hwmon*/fan*_input` files.
+///
+/// See [fans] function.
+///
+/// [fans]: ./fn.fans.html
+#[derive(Debug, Clone)]
+pub struct Fan {
+    pub(crate) unit: String,
+    pub(crate) label: Option<String>,
+    pub(crate) speed: u64,
+}
+
+impl Fan {
+    /// Returns `hwmon` driver name, ex. `thinkpad` or `nct6775`.
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+
+    /// Returns fan label, if available.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Returns current fan speed in revolutions per minute (RPM).
+    pub fn speed(&self) -> u64 {
+        self.speed
+    }
+}
+
+/// Returns a stream over the [fan] speed sensors.
+///
+/// Fans which speed can't be read at the moment are skipped.
+///
+/// Empty stream is returned if there are no fan sensors available.
+///
+/// [fan]: ./struct.Fan.html
+#[cfg(target_os = "linux")]
+pub fn fans() -> impl Stream<Item = Result<Fan>> {
+    crate::sys::fans()
+}
+
+/// Battery charging state.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum BatteryState {
+    /// Battery is charging.
+    Charging,
+    /// Battery is discharging.
+    Discharging,
+    /// Battery is fully charged.
+    Full,
+    /// Power supply is connected, but battery is not charging,
+    /// ex. because of the charge thresholds.
+    NotCharging,
+    /// State is unknown.
+    Unknown,
+}
+
+/// Battery information, as exposed by the `/sys/class/power_supply/` directory.
+///
+/// See [battery] function.
+///
+/// [battery]: ./fn.battery.html
+#[derive(Debug, Clone)]
+pub struct Battery {
+    pub(crate) name: String,
+    pub(crate) charge: Ratio,
+    pub(crate) state: BatteryState,
+}
+
+impl Battery {
+    /// Returns power supply name, ex. `BAT0`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns battery charge level.
+    pub fn charge(&self) -> Ratio {
+        self.charge
+    }
+
+    /// Returns battery charging state.
+    pub fn state(&self) -> BatteryState {
+        self.state
+    }
+}
+
+/// Returns system [battery] information.
+///
+/// First power supply of the `Battery` type is used, except for the peripheral devices
+/// batteries (ex. wireless mouse). Charge level is read from the `capacity` file
+/// or calculated from the `energy_now` / `energy_full` (or `charge_now` / `charge_full`) values.
+///
+/// `Ok(None)` is returned if there is no battery in the system.
+///
+/// [battery]: ./struct.Battery.html
+#[cfg(target_os = "linux")]
+pub async fn battery() -> Result<Option<Battery>> {
+    crate::sys::battery().await
+}
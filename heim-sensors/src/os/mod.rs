@@ -0,0 +1,5 @@
+//! OS-specific extensions.
+
+#[cfg(any(target_os = "linux", doc))]
+#[cfg_attr(docsrs, doc(cfg(target_os = "linux")))]
+pub mod linux;
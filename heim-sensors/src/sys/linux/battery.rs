@@ -0,0 +1,122 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use heim_common::prelude::*;
+use heim_common::sys::linux::read_value;
+use heim_common::units::{ratio, Ratio};
+use heim_runtime as rt;
+
+use crate::os::linux::{Battery, BatteryState};
+
+// Battery attributes set differs between the drivers, and some of them
+// might fail to read while the device is suspended, so any error is ignored here
+fn read_string(path: &Path) -> Option<String> {
+    read_value(path).ok().flatten()
+}
+
+fn read_number(path: &Path) -> Option<f32> {
+    read_string(path)?.parse().ok()
+}
+
+fn battery_state(value: &str) -> BatteryState {
+    match value {
+        "Charging" => BatteryState::Charging,
+        "Discharging" => BatteryState::Discharging,
+        "Full" => BatteryState::Full,
+        "Not charging" => BatteryState::NotCharging,
+        _ => BatteryState::Unknown,
+    }
+}
+
+// Depending on the driver, charge level is reported either as a percentage,
+// or as the energy (µWh) or charge (µAh) amounts
+fn charge(capacity: Option<f32>, now: Option<f32>, full: Option<f32>) -> Option<Ratio> {
+    match (capacity, now, full) {
+        (Some(capacity), _, _) => Some(Ratio::new::<ratio::percent>(capacity)),
+        (None, Some(now), Some(full)) if full > 0.0 => {
+            Some(Ratio::new::<ratio::ratio>((now / full).min(1.0)))
+        }
+        _ => None,
+    }
+}
+
+fn read_battery(root: &Path, name: String) -> Option<Battery> {
+    if read_string(&root.join("type"))?.as_str() != "Battery" {
+        return None;
+    }
+    // Peripheral devices (ex. wireless mouse) batteries are having the `Device` scope
+    if read_string(&root.join("scope")).as_deref() == Some("Device") {
+        return None;
+    }
+
+    let (now, full) = match read_number(&root.join("energy_now")) {
+        Some(now) => (Some(now), read_number(&root.join("energy_full"))),
+        None => (
+            read_number(&root.join("charge_now")),
+            read_number(&root.join("charge_full")),
+        ),
+    };
+    let charge = charge(read_number(&root.join("capacity")), now, full)?;
+    let state = read_string(&root.join("status"))
+        .map(|value| battery_state(&value))
+        .unwrap_or(BatteryState::Unknown);
+
+    Some(Battery {
+        name,
+        charge,
+        state,
+    })
+}
+
+fn read_batteries() -> Result<Option<Battery>> {
+    let root = rt::linux::sysfs_root().join("class/power_supply");
+    let entries = match fs::read_dir(&root) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(Error::from(e).with_file(root)),
+    };
+
+    let mut names = Vec::new();
+    for entry in entries {
+        names.push(entry?.file_name().to_string_lossy().into_owned());
+    }
+    names.sort();
+
+    Ok(names
+        .into_iter()
+        .find_map(|name| read_battery(&root.join(&name), name)))
+}
+
+pub async fn battery() -> Result<Option<Battery>> {
+    rt::spawn_blocking(read_batteries).await
+}
+
+#[cfg(test)]
+mod tests {
+    use heim_common::units::ratio;
+
+    use super::{battery_state, charge};
+    use crate::os::linux::BatteryState;
+
+    #[test]
+    fn test_battery_state() {
+        assert_eq!(battery_state("Charging"), BatteryState::Charging);
+        assert_eq!(battery_state("Discharging"), BatteryState::Discharging);
+        assert_eq!(battery_state("Full"), BatteryState::Full);
+        assert_eq!(battery_state("Not charging"), BatteryState::NotCharging);
+        assert_eq!(battery_state("Unknown"), BatteryState::Unknown);
+    }
+
+    #[test]
+    fn test_charge() {
+        let value = charge(Some(87.0), Some(1.0), Some(2.0)).unwrap();
+        assert!((value.get::<ratio::percent>() - 87.0).abs() < 0.001);
+
+        let value = charge(None, Some(30_000_000.0), Some(40_000_000.0)).unwrap();
+        assert!((value.get::<ratio::percent>() - 75.0).abs() < 0.001);
+
+        assert!(charge(None, Some(1.0), Some(0.0)).is_none());
+        assert!(charge(None, None, None).is_none());
+    }
+}
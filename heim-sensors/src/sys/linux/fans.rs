@@ -0,0 +1,97 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use heim_common::prelude::*;
+use heim_common::sys::linux::read_value;
+use heim_runtime as rt;
+
+use crate::os::linux::Fan;
+
+// Fan inputs are named as `fan1_input`, where `1` is the fan index
+fn fan_index(name: &str) -> Option<u32> {
+    name.strip_prefix("fan")?
+        .strip_suffix("_input")?
+        .parse()
+        .ok()
+}
+
+fn read_hwmon(root: &Path) -> Result<Vec<Fan>> {
+    let mut indices = Vec::new();
+    for entry in fs::read_dir(root).map_err(|e| Error::from(e).with_file(root))? {
+        if let Some(index) = entry?.file_name().to_str().and_then(fan_index) {
+            indices.push(index);
+        }
+    }
+    if indices.is_empty() {
+        return Ok(vec![]);
+    }
+    indices.sort_unstable();
+
+    let unit = match read_value(root.join("name"))? {
+        Some(unit) => unit,
+        None => return Err(Error::missing_key("name", format!("{}", root.display()))),
+    };
+    let mut acc = Vec::with_capacity(indices.len());
+    for index in indices {
+        // Reading speed might fail if the fan is disconnected or the device is suspended
+        let speed = match read_value(root.join(format!("fan{}_input", index))) {
+            Ok(Some(value)) => value.parse::<u64>()?,
+            _ => continue,
+        };
+        let label = read_value(root.join(format!("fan{}_label", index)))
+            .ok()
+            .flatten()
+            .filter(|label| !label.is_empty());
+
+        acc.push(Fan {
+            unit: unit.clone(),
+            label,
+            speed,
+        });
+    }
+
+    Ok(acc)
+}
+
+fn read_fans() -> Result<Vec<Fan>> {
+    let root = rt::linux::sysfs_root().join("class/hwmon");
+    let entries = match fs::read_dir(&root) {
+        Ok(entries) => entries,
+        // Kernel was built without hardware monitoring drivers
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(Error::from(e).with_file(root)),
+    };
+
+    let mut paths = Vec::new();
+    for entry in entries {
+        paths.push(entry?.path());
+    }
+    paths.sort();
+
+    let mut acc = Vec::new();
+    for path in paths {
+        acc.extend(read_hwmon(&path)?);
+    }
+
+    Ok(acc)
+}
+
+pub fn fans() -> impl Stream<Item = Result<Fan>> {
+    stream::once(rt::spawn_blocking(read_fans))
+        .map_ok(|fans| stream::iter(fans.into_iter().map(Ok)))
+        .try_flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fan_index;
+
+    #[test]
+    fn test_fan_index() {
+        assert_eq!(fan_index("fan1_input"), Some(1));
+        assert_eq!(fan_index("fan12_input"), Some(12));
+        assert_eq!(fan_index("fan1_label"), None);
+        assert_eq!(fan_index("temp1_input"), None);
+    }
+}
@@ -1,3 +1,9 @@
+mod battery;
+mod fans;
 mod temperatures;
+mod thermal_zones;
 
+pub use self::battery::*;
+pub use self::fans::*;
 pub use self::temperatures::*;
+pub use self::thermal_zones::*;
@@ -1,13 +1,15 @@
 use std::ffi::{OsStr, OsString};
+use std::io;
 use std::os::unix::ffi::OsStrExt;
 use std::path::PathBuf;
 
 use heim_common::prelude::*;
 use heim_common::units::{thermodynamic_temperature, ThermodynamicTemperature};
 use heim_common::utils::stream::HeimStreamExt;
+use heim_common::Temperature;
 use heim_runtime as rt;
 
-use crate::TemperatureSensor;
+use crate::{TemperatureSensor, TemperatureSource};
 
 #[inline]
 fn file_name(prefix: &OsStr, postfix: &[u8]) -> OsString {
@@ -18,6 +20,39 @@ fn file_name(prefix: &OsStr, postfix: &[u8]) -> OsString {
     name
 }
 
+// Class directory is missing if there are no drivers registered for it
+fn class_entries(name: &str) -> impl Stream<Item = io::Result<rt::fs::DirEntry>> {
+    rt::fs::read_dir(rt::linux::sysfs_root().join("class").join(name))
+        .map(|result| match result {
+            Ok(entries) => Ok(entries.left_stream()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(stream::empty().right_stream()),
+            Err(e) => Err(e),
+        })
+        .try_flatten_stream()
+}
+
+// Guessing the sensor source by the `hwmon` driver name or thermal zone type
+fn source(unit: &str) -> TemperatureSource {
+    match unit {
+        "coretemp" | "k8temp" | "k10temp" | "zenpower" | "via_cputemp" | "x86_pkg_temp" => {
+            TemperatureSource::Cpu
+        }
+        unit if unit.starts_with("cpu") => TemperatureSource::Cpu,
+        "drivetemp" | "nvme" => TemperatureSource::Disk,
+        _ => TemperatureSource::Other,
+    }
+}
+
+// Some sensors might be readable by the privileged users only,
+// these are skipped instead of terminating the whole stream
+fn permitted<T>(result: Result<T>) -> Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(e) if e.is_permission_denied() => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
 async fn read_temperature(path: PathBuf) -> Result<ThermodynamicTemperature> {
     let contents = rt::fs::read_to_string(path).await?;
     // Originally value is in millidegrees of Celsius
@@ -28,6 +63,17 @@ async fn read_temperature(path: PathBuf) -> Result<ThermodynamicTemperature> {
     >(value / 1_000.0))
 }
 
+// Sensors without the `tempN_label` file are named after their files prefix, ex. `temp1`
+fn default_label(prefix: &OsStr) -> String {
+    let bytes = prefix.as_bytes();
+    let name = match bytes.split_last() {
+        Some((b'_', name)) => name,
+        _ => bytes,
+    };
+
+    String::from_utf8_lossy(name).into_owned()
+}
+
 async fn hwmon_sensor(input: PathBuf) -> Result<TemperatureSensor> {
     // It is guaranteed by `hwmon` and `hwmon_sensor` directory traversals,
     // that it is not a root directory and it points to a file.
@@ -48,6 +94,7 @@ async fn hwmon_sensor(input: PathBuf) -> Result<TemperatureSensor> {
             let _ = string.pop();
             string
         });
+    let fallback_label = default_label(prefix);
     let label = rt::fs::read_to_string(root.join(file_name(prefix, b"label")))
         .map_err(Error::from)
         .map_ok(|mut string| {
@@ -55,9 +102,10 @@ async fn hwmon_sensor(input: PathBuf) -> Result<TemperatureSensor> {
             let _ = string.pop();
             Some(string)
         })
-        .or_else(|_e| {
+        .or_else(|e| match e.kind() {
+            io::ErrorKind::NotFound => future::ok(Some(fallback_label)),
             // TODO: Would it be reasonable to propagate errors other than NotFound?
-            future::ok::<_, Error>(None)
+            _ => future::ok::<_, Error>(None),
         });
     let high = read_temperature(root.join(file_name(prefix, b"max")))
         .map_ok(Some)
@@ -75,11 +123,12 @@ async fn hwmon_sensor(input: PathBuf) -> Result<TemperatureSensor> {
 
     future::try_join5(unit_name, label, current, high, critical)
         .map_ok(|(unit, label, current, high, critical)| TemperatureSensor {
+            source: source(&unit),
             unit,
-            label,
-            current,
-            high,
-            critical,
+            temperature: Temperature::new(current)
+                .with_label(label)
+                .with_high(high)
+                .with_critical(critical),
         })
         .await
 }
@@ -87,8 +136,7 @@ async fn hwmon_sensor(input: PathBuf) -> Result<TemperatureSensor> {
 fn hwmon() -> impl Stream<Item = Result<TemperatureSensor>> {
     // TODO: It would be nice to have async glob matchers :(
     // Basically we are searching for `/sys/class/hwmon/temp*_*` files here
-    rt::fs::read_dir(rt::linux::sysfs_root().join("class/hwmon"))
-        .try_flatten_stream()
+    class_entries("hwmon")
         .try_filter(|entry| future::ready(entry.file_name().as_bytes().starts_with(b"hwmon")))
         .and_then(|entry| {
             let inner = rt::fs::read_dir(entry.path())
@@ -104,7 +152,7 @@ fn hwmon() -> impl Stream<Item = Result<TemperatureSensor>> {
         })
         .try_flatten()
         .map_err(Error::from)
-        .and_then(|entry| hwmon_sensor(entry.path()))
+        .try_filter_map(|entry| hwmon_sensor(entry.path()).map(permitted))
 }
 
 // CentOS has an intermediate /device directory:
@@ -113,8 +161,7 @@ fn hwmon() -> impl Stream<Item = Result<TemperatureSensor>> {
 fn hwmon_device() -> impl Stream<Item = Result<TemperatureSensor>> {
     // TODO: It would be nice to have async glob matchers :(
     // Basically we are searching for `/sys/class/hwmon/temp*_*` files here
-    rt::fs::read_dir(rt::linux::sysfs_root().join("class/hwmon"))
-        .try_flatten_stream()
+    class_entries("hwmon")
         .try_filter(|entry| future::ready(entry.file_name().as_bytes().starts_with(b"hwmon")))
         .try_filter(|entry| {
             // TODO: `entry.path()` allocates memory for `PathBuf` twice
@@ -135,13 +182,12 @@ fn hwmon_device() -> impl Stream<Item = Result<TemperatureSensor>> {
         })
         .try_flatten()
         .map_err(Error::from)
-        .and_then(|entry| hwmon_sensor(entry.path()))
+        .try_filter_map(|entry| hwmon_sensor(entry.path()).map(permitted))
 }
 
 // https://www.kernel.org/doc/Documentation/thermal/sysfs-api.txt
 fn thermal_zone() -> impl Stream<Item = Result<TemperatureSensor>> {
-    rt::fs::read_dir(rt::linux::sysfs_root().join("class/thermal"))
-        .try_flatten_stream()
+    class_entries("thermal")
         .try_filter(|entry| {
             future::ready(entry.file_name().as_bytes().starts_with(b"thermal_zone"))
         })
@@ -157,15 +203,16 @@ fn thermal_zone() -> impl Stream<Item = Result<TemperatureSensor>> {
                     string
                 });
 
-            future::try_join(temperature, unit_name).map_ok(|(temp, unit)| (root, temp, unit))
+            future::try_join(temperature, unit_name)
+                .map_ok(|(temp, unit)| (root, temp, unit))
+                .map(permitted)
         })
+        .try_filter_map(future::ok)
         .and_then(|(root, temp, unit)| {
             let sensor = TemperatureSensor {
+                source: source(&unit),
                 unit,
-                label: None,
-                current: temp,
-                high: None,
-                critical: None,
+                temperature: Temperature::new(temp),
             };
 
             rt::fs::read_dir(root)
@@ -193,13 +240,13 @@ fn thermal_zone() -> impl Stream<Item = Result<TemperatureSensor>> {
                         .and_then(move |content| match content.as_str() {
                             "critical\n" => read_temperature(temp_path)
                                 .and_then(move |temp| {
-                                    acc.critical = Some(temp);
+                                    acc.temperature = acc.temperature.with_critical(Some(temp));
                                     future::ok(acc)
                                 })
                                 .boxed(),
                             "high\n" => read_temperature(temp_path)
                                 .and_then(move |temp| {
-                                    acc.high = Some(temp);
+                                    acc.temperature = acc.temperature.with_high(Some(temp));
                                     future::ok(acc)
                                 })
                                 .boxed(),
@@ -215,3 +262,27 @@ pub fn temperatures() -> impl Stream<Item = Result<TemperatureSensor>> {
     // We need the `thermal_zone` items, only if `hwmon` stream yielded nothing
     hwmon.choose_chain(thermal_zone())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsStr;
+
+    use super::{default_label, source};
+    use crate::TemperatureSource;
+
+    #[test]
+    fn test_default_label() {
+        assert_eq!(default_label(OsStr::new("temp1_")), "temp1");
+        assert_eq!(default_label(OsStr::new("temp12_")), "temp12");
+    }
+
+    #[test]
+    fn test_source() {
+        assert_eq!(source("coretemp"), TemperatureSource::Cpu);
+        assert_eq!(source("k10temp"), TemperatureSource::Cpu);
+        assert_eq!(source("cpu_thermal"), TemperatureSource::Cpu);
+        assert_eq!(source("drivetemp"), TemperatureSource::Disk);
+        assert_eq!(source("nvme"), TemperatureSource::Disk);
+        assert_eq!(source("acpitz"), TemperatureSource::Other);
+    }
+}
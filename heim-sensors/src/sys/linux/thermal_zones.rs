@@ -0,0 +1,128 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use heim_common::prelude::*;
+use heim_common::sys::linux::read_value;
+use heim_common::units::{thermodynamic_temperature, ThermodynamicTemperature};
+use heim_runtime as rt;
+
+use crate::os::linux::{ThermalZone, TripPoint, TripPointKind};
+
+fn read_temperature(path: &Path) -> Result<ThermodynamicTemperature> {
+    // Originally value is in millidegrees of Celsius
+    let value = match read_value(path)? {
+        Some(value) => value.parse::<f32>()?,
+        None => {
+            return Err(Error::missing_key(
+                "temperature",
+                format!("{}", path.display()),
+            ))
+        }
+    };
+
+    Ok(ThermodynamicTemperature::new::<
+        thermodynamic_temperature::degree_celsius,
+    >(value / 1_000.0))
+}
+
+fn trip_point_kind(value: &str) -> Option<TripPointKind> {
+    match value {
+        "critical" => Some(TripPointKind::Critical),
+        "hot" => Some(TripPointKind::Hot),
+        "passive" => Some(TripPointKind::Passive),
+        "active" => Some(TripPointKind::Active),
+        _ => None,
+    }
+}
+
+fn read_trip_points(root: &Path) -> Vec<TripPoint> {
+    let mut acc = Vec::new();
+    // Trip points are numbered sequentially, starting from zero
+    for index in 0.. {
+        let kind = match read_value(root.join(format!("trip_point_{}_type", index))) {
+            Ok(Some(kind)) => kind,
+            _ => break,
+        };
+        let kind = match trip_point_kind(&kind) {
+            Some(kind) => kind,
+            None => continue,
+        };
+        // Disabled trip points might fail to read
+        if let Ok(temperature) = read_temperature(&root.join(format!("trip_point_{}_temp", index)))
+        {
+            acc.push(TripPoint { kind, temperature });
+        }
+    }
+
+    acc
+}
+
+fn read_zone(root: &Path) -> Result<Option<ThermalZone>> {
+    // Reading temperature might fail if the device is suspended or not ready yet
+    let temperature = match read_temperature(&root.join("temp")) {
+        Ok(temperature) => temperature,
+        Err(_) => return Ok(None),
+    };
+    let name = match read_value(root.join("type"))? {
+        Some(name) => name,
+        None => return Err(Error::missing_key("type", format!("{}", root.display()))),
+    };
+
+    Ok(Some(ThermalZone {
+        name,
+        temperature,
+        trip_points: read_trip_points(root),
+    }))
+}
+
+fn read_zones() -> Result<Vec<ThermalZone>> {
+    let root = rt::linux::sysfs_root().join("class/thermal");
+    let entries = match fs::read_dir(&root) {
+        Ok(entries) => entries,
+        // Kernel was built without thermal drivers
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(Error::from(e).with_file(root)),
+    };
+
+    let mut paths = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        if entry
+            .file_name()
+            .to_string_lossy()
+            .starts_with("thermal_zone")
+        {
+            paths.push(entry.path());
+        }
+    }
+    paths.sort();
+
+    let mut acc = Vec::with_capacity(paths.len());
+    for path in paths {
+        if let Some(zone) = read_zone(&path)? {
+            acc.push(zone);
+        }
+    }
+
+    Ok(acc)
+}
+
+pub fn thermal_zones() -> impl Stream<Item = Result<ThermalZone>> {
+    stream::once(rt::spawn_blocking(read_zones))
+        .map_ok(|zones| stream::iter(zones.into_iter().map(Ok)))
+        .try_flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::trip_point_kind;
+    use crate::os::linux::TripPointKind;
+
+    #[test]
+    fn test_trip_point_kind() {
+        assert_eq!(trip_point_kind("critical"), Some(TripPointKind::Critical));
+        assert_eq!(trip_point_kind("passive"), Some(TripPointKind::Passive));
+        assert_eq!(trip_point_kind("unknown"), None);
+    }
+}
@@ -2,16 +2,29 @@ use std::fmt;
 
 use heim_common::prelude::*;
 use heim_common::units::ThermodynamicTemperature;
+use heim_common::Temperature;
 
 use crate::sys;
 
+/// Hardware component which [temperature sensor] belongs to.
+///
+/// [temperature sensor]: ./struct.TemperatureSensor.html
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum TemperatureSource {
+    /// CPU package or core sensor, ex. `coretemp` or `k10temp` drivers.
+    Cpu,
+    /// Storage device sensor, ex. `drivetemp` or `nvme` drivers.
+    Disk,
+    /// Any other sensor, ex. chipset or ACPI thermal zone.
+    Other,
+}
+
 /// Hardware temperature sensor.
 pub struct TemperatureSensor {
     pub(crate) unit: String,
-    pub(crate) label: Option<String>,
-    pub(crate) current: ThermodynamicTemperature,
-    pub(crate) high: Option<ThermodynamicTemperature>,
-    pub(crate) critical: Option<ThermodynamicTemperature>,
+    pub(crate) source: TemperatureSource,
+    pub(crate) temperature: Temperature,
 }
 
 impl TemperatureSensor {
@@ -20,25 +33,36 @@ impl TemperatureSensor {
         &self.unit
     }
 
+    /// Returns hardware component the sensor belongs to.
+    pub fn source(&self) -> TemperatureSource {
+        self.source
+    }
+
+    /// Returns sensor [temperature] reading.
+    ///
+    /// [temperature]: ./struct.Temperature.html
+    pub fn temperature(&self) -> &Temperature {
+        &self.temperature
+    }
+
     /// Returns sensor label.
-    #[allow(clippy::option_as_ref_deref)] // >= 1.40.0
     pub fn label(&self) -> Option<&str> {
-        self.label.as_ref().map(|s| s.as_str())
+        self.temperature.label()
     }
 
     /// Returns current temperature reported by sensor.
     pub fn current(&self) -> ThermodynamicTemperature {
-        self.current
+        self.temperature.current()
     }
 
     /// Returns high trip point for sensor if available.
     pub fn high(&self) -> Option<ThermodynamicTemperature> {
-        self.high
+        self.temperature.high()
     }
 
     /// Returns critical trip point for sensor if available.
     pub fn critical(&self) -> Option<ThermodynamicTemperature> {
-        self.critical
+        self.temperature.critical()
     }
 }
 
@@ -46,6 +70,7 @@ impl fmt::Debug for TemperatureSensor {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("TemperatureSensor")
             .field("unit", &self.unit())
+            .field("source", &self.source())
             .field("label", &self.label())
             .field("current", &self.current())
             .field("high", &self.high())
@@ -56,12 +81,34 @@ impl fmt::Debug for TemperatureSensor {
 
 /// Returns a stream over the [temperature sensors] statistics.
 ///
+/// CPU, storage devices and all other sensors readings are yielded in one stream,
+/// use [`TemperatureSensor::source`] to tell them apart.
+///
+/// Empty stream is returned if there are no sensors available.
+/// Sensors which are not readable due to the insufficient permissions are skipped.
+///
 /// ## Compatibility
 ///
-/// At the moment, this function works only with Linux.
+/// At the moment, this function works only with Linux,
+/// where all `/sys/class/hwmon` sensors are walked
+/// (or `/sys/class/thermal` zones if there are no `hwmon` sensors).
 /// For other platforms it returns an empty stream.
 ///
+/// [`TemperatureSensor::source`]: ./struct.TemperatureSensor.html#method.source
+///
 /// [temperature sensors]: ./struct.TemperatureSensor.html
 pub fn temperatures() -> impl Stream<Item = Result<TemperatureSensor>> {
     sys::temperatures()
 }
+
+/// Returns a stream over the CPU package and cores [temperature sensors].
+///
+/// Same as [`temperatures`], but yields only the sensors
+/// with the [`TemperatureSource::Cpu`] source.
+///
+/// [`temperatures`]: ./fn.temperatures.html
+/// [`TemperatureSource::Cpu`]: ./enum.TemperatureSource.html#variant.Cpu
+/// [temperature sensors]: ./struct.TemperatureSensor.html
+pub fn cpu_temperatures() -> impl Stream<Item = Result<TemperatureSensor>> {
+    temperatures().try_filter(|sensor| future::ready(sensor.source() == TemperatureSource::Cpu))
+}
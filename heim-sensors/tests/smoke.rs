@@ -8,9 +8,59 @@ async fn smoke_temperatures() {
         let sensor = sensor.unwrap();
 
         let _ = sensor.unit();
+        let _ = sensor.source();
         let _ = sensor.label();
         let _ = sensor.current();
         let _ = sensor.high();
         let _ = sensor.critical();
+        let _ = sensor.temperature().to_string();
+    }
+}
+
+#[heim_derive::test]
+async fn smoke_cpu_temperatures() {
+    let mut temperatures = sensors::cpu_temperatures().boxed_local();
+    while let Some(sensor) = temperatures.next().await {
+        assert_eq!(sensor.unwrap().source(), sensors::TemperatureSource::Cpu);
+    }
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_thermal_zones() {
+    let mut zones = sensors::os::linux::thermal_zones().boxed_local();
+    while let Some(zone) = zones.next().await {
+        let zone = zone.unwrap();
+
+        let _ = zone.name();
+        let _ = zone.temperature();
+        for trip_point in zone.trip_points() {
+            let _ = trip_point.kind();
+            let _ = trip_point.temperature();
+        }
+    }
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_fans() {
+    let mut fans = sensors::os::linux::fans().boxed_local();
+    while let Some(fan) = fans.next().await {
+        let fan = fan.unwrap();
+
+        let _ = fan.unit();
+        let _ = fan.label();
+        let _ = fan.speed();
+    }
+}
+
+#[heim_derive::test]
+#[cfg(target_os = "linux")]
+async fn smoke_battery() {
+    if let Some(battery) = sensors::os::linux::battery().await.unwrap() {
+        let _ = battery.name();
+        let _ = battery.state();
+        let charge = battery.charge().get::<heim_common::units::ratio::ratio>();
+        assert!((0.0..=1.0).contains(&charge));
     }
 }
@@ -31,6 +31,15 @@
 
 mod sys;
 
+/// Re-exports of the commonly used virtualization types and the measurement units for them.
+///
+/// ```rust
+/// use heim_virt::prelude::*;
+/// ```
+pub mod prelude {
+    pub use crate::Virtualization;
+}
+
 /// Virtualization systems (both VMs and containers)
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Clone, Hash)]
 #[non_exhaustive]
@@ -107,6 +107,21 @@ where
     }
 }
 
+async fn detect_marker_files<T>(root: T) -> Result<Virtualization, ()>
+where
+    T: AsRef<Path>,
+{
+    // Container engines are dropping these files into the container root
+    let f1 = rt::fs::path_exists(root.as_ref().join(".dockerenv"));
+    let f2 = rt::fs::path_exists(root.as_ref().join("run/.containerenv"));
+
+    match futures::join!(f1, f2) {
+        (true, _) => Ok(Virtualization::Docker),
+        (_, true) => Ok(Virtualization::Podman),
+        _ => Err(()),
+    }
+}
+
 pub async fn detect_container() -> Result<Virtualization, ()> {
     future::err(())
         .or_else(|_| detect_openvz())
@@ -114,13 +129,14 @@ pub async fn detect_container() -> Result<Virtualization, ()> {
         .or_else(|_| detect_systemd_container("/run/systemd/container"))
         .or_else(|_| detect_init_env("/proc/1/environ"))
         // TODO: Check for a `/proc/1/environ` if there is `container` env var exists
+        .or_else(|_| detect_marker_files("/"))
         .or_else(|_| detect_cgroups("/proc/self/cgroup"))
         .await
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{detect_init_env, detect_wsl};
+    use super::{detect_init_env, detect_marker_files, detect_wsl};
     use std::io::Write;
 
     use crate::Virtualization;
@@ -152,4 +168,23 @@ mod tests {
 
         assert_eq!(res, Ok(Virtualization::Podman))
     }
+
+    #[heim_derive::test]
+    async fn test_marker_files() {
+        let root = tempfile::tempdir().unwrap();
+        assert_eq!(detect_marker_files(root.path()).await, Err(()));
+
+        std::fs::create_dir(root.path().join("run")).unwrap();
+        let _ = std::fs::File::create(root.path().join("run/.containerenv")).unwrap();
+        assert_eq!(
+            detect_marker_files(root.path()).await,
+            Ok(Virtualization::Podman)
+        );
+
+        let _ = std::fs::File::create(root.path().join(".dockerenv")).unwrap();
+        assert_eq!(
+            detect_marker_files(root.path()).await,
+            Ok(Virtualization::Docker)
+        );
+    }
 }
@@ -32,6 +32,12 @@
 //!
 //! Alternatively you can use `full` feature to enable all components at once.
 //!
+//! `serde` feature implements `Serialize` for the `cpu`, `disk`, `host` and `net` components
+//! types (enabling these components as well), with the measurement units serialized
+//! as plain numbers in the canonical units, see [serialize] module for details.
+//!
+//! [serialize]: ./serialize/index.html
+//!
 //! ## Documentation
 //!
 //! Note that `heim` also provides platform-specific APIs.
@@ -107,8 +113,12 @@ pub use heim_virt as virt;
 #[cfg_attr(docsrs, doc(cfg(feature = "sensors")))]
 pub use heim_sensors as sensors;
 
+pub use heim_common::human;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use heim_common::serialize;
 pub use heim_common::units;
-pub use heim_common::{Error, Result};
+pub use heim_common::{stream_with_timeout, with_timeout, Error, Result};
 
 pub mod os {
     //! Runtime specific re-exports.
@@ -119,6 +129,71 @@ pub mod os {
         //! Available only for `cfg(target_os = "linux")`
         #[doc(inline)]
         #[cfg_attr(docsrs, doc(cfg(target_os = "linux")))]
-        pub use heim_runtime::linux::{procfs_root, set_procfs_root, set_sysfs_root, sysfs_root};
+        pub use heim_runtime::linux::{
+            procfs_root, set_procfs_root, set_sysfs_root, sysfs_root, PROCFS_ROOT_ENV,
+            SYSFS_ROOT_ENV,
+        };
     }
 }
+
+pub mod prelude {
+    //! Re-exports of the commonly used types and measurement units.
+    //!
+    //! Same as modules, types are available only if the corresponding feature is enabled.
+    //!
+    //! ```rust
+    //! use heim::prelude::*;
+    //! ```
+    //!
+    //! Types with the same names in the different components
+    //! (ex. [`disk::IoCounters`] and [`net::IoCounters`])
+    //! are re-exported with the component name prefix, ex. `DiskIoCounters` and `NetIoCounters`.
+    //!
+    //! [`disk::IoCounters`]: ../disk/struct.IoCounters.html
+    //! [`net::IoCounters`]: ../net/struct.IoCounters.html
+
+    pub use crate::units::{
+        frequency, information, information_rate, ratio, thermodynamic_temperature, time,
+        Frequency, Information, InformationRate, Ratio, ThermodynamicTemperature, Time,
+    };
+
+    #[cfg(feature = "cpu")]
+    pub use crate::cpu::{self, Cache, CacheKind, CpuFrequency, CpuStats, CpuTime, CpuUsage};
+
+    #[cfg(feature = "disk")]
+    pub use crate::disk::{
+        self, BlockDevice, FileSystem, IoCounters as DiskIoCounters,
+        IoCountersDelta as DiskIoCountersDelta, IoRates as DiskIoRates, Partition, Usage,
+    };
+
+    #[cfg(feature = "host")]
+    pub use crate::host::{self, Arch, Platform, User};
+
+    #[cfg(feature = "memory")]
+    pub use crate::memory::{self, Memory, Swap};
+
+    #[cfg(feature = "net")]
+    pub use crate::net::{
+        self, Address, Connection, ConnectionProtocol, IcmpCounters, IoCounters as NetIoCounters,
+        IoRates as NetIoRates, IpCounters, MacAddr, Nic, ProtocolCounters, TcpCounters, TcpState,
+        UdpCounters,
+    };
+
+    #[cfg(feature = "process")]
+    pub use crate::process::{
+        self, Command, CpuTime as ProcessCpuTime, CpuUsage as ProcessCpuUsage, Environment,
+        Memory as ProcessMemory, Process, ProcessError, ProcessResult, Status,
+    };
+
+    #[cfg(feature = "process")]
+    pub use crate::process::prelude::IoCounters as ProcessIoCounters;
+
+    #[cfg(any(feature = "host", feature = "process"))]
+    pub use heim_common::Pid;
+
+    #[cfg(feature = "sensors")]
+    pub use crate::sensors::{self, Temperature, TemperatureSensor, TemperatureSource};
+
+    #[cfg(feature = "virt")]
+    pub use crate::virt::{self, Virtualization};
+}
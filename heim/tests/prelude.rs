@@ -0,0 +1,14 @@
+#![cfg(feature = "process")]
+
+use heim::prelude::*;
+
+// Fails to compile if the alias resolves to some other `IoCounters` type
+#[allow(dead_code)]
+fn process_io_counters(counters: &ProcessIoCounters) -> (Information, Information) {
+    (counters.bytes_read(), counters.bytes_written())
+}
+
+#[test]
+fn test_process_io_counters_alias() {
+    let _ = process_io_counters as fn(&ProcessIoCounters) -> _;
+}